@@ -1,5 +1,5 @@
 
-use crate::{EAmountMode, AmountParam, steps::EStepMode, curve::{KeyFrameCurveValue, FrameIndex}, easing::EEasingMode};
+use crate::{EAmountMode, spring::{spring_omega_zeta, spring_settling_duration}, AmountParam, steps::EStepMode, curve::{KeyFrameCurveValue, FrameIndex}, easing::EEasingMode};
 
 /// 动画进度计算器
 pub struct AnimationAmountCalc {
@@ -9,6 +9,10 @@ pub struct AnimationAmountCalc {
     param: AmountParam,
     // 进度曲线函数,创建时赋值,不在运行时对mode进行匹配,获得更好性能
     call: fn(KeyFrameCurveValue, &AmountParam) -> KeyFrameCurveValue,
+    // 进度计算入口,创建时赋值; 分段线性模式直接读取停靠点, 其他模式使用 call 与 param
+    calc_call: fn(&AnimationAmountCalc, KeyFrameCurveValue) -> KeyFrameCurveValue,
+    // 分段线性停靠点 [Linear], 依次存放 所有停靠点的输入进度, 所有停靠点的输出进度
+    linear_stops: Vec<KeyFrameCurveValue>,
 }
 
 impl Default for AnimationAmountCalc {
@@ -17,6 +21,8 @@ impl Default for AnimationAmountCalc {
             mode: EAmountMode::None,
             param: AmountParam::default(),
             call: EAmountMode::get_transform_amount_call(EAmountMode::None),
+            calc_call: AnimationAmountCalc::calc_param,
+            linear_stops: Vec::new(),
         }
     }
 }
@@ -35,6 +41,8 @@ impl AnimationAmountCalc {
                 mode,
                 param: AmountParam(step as KeyFrameCurveValue, 0., 0., 0.),
                 call: EAmountMode::get_transform_amount_call(mode),
+                calc_call: Self::calc_param,
+                linear_stops: Vec::new(),
             }
         }
     }
@@ -45,6 +53,8 @@ impl AnimationAmountCalc {
             mode,
             param: AmountParam::default(),
             call: EAmountMode::get_transform_amount_call(mode),
+            calc_call: Self::calc_param,
+            linear_stops: Vec::new(),
         }
     }
     /// 创建一个三次贝塞尔曲线模式的进度计算器
@@ -54,11 +64,108 @@ impl AnimationAmountCalc {
             mode,
             param: AmountParam(x1 as KeyFrameCurveValue, y1 as KeyFrameCurveValue, x2 as KeyFrameCurveValue, y2 as KeyFrameCurveValue),
             call: EAmountMode::get_transform_amount_call(mode),
+            calc_call: Self::calc_param,
+            linear_stops: Vec::new(),
+        }
+    }
+    /// 创建一个弹簧模式的进度计算器
+    /// 进度 [0, 1] 对应弹簧从起始到稳定的时长, 稳定时长在创建时计算一次, 可由 spring_duration 获得
+    ///
+    /// * [mass] - 质量
+    /// * [stiffness] - 刚度
+    /// * [damping] - 阻尼
+    /// * [velocity] - 初始速度, 以 进度/秒 为单位
+    ///
+    pub fn from_spring(mass: KeyFrameCurveValue, stiffness: KeyFrameCurveValue, damping: KeyFrameCurveValue, velocity: KeyFrameCurveValue) -> Self {
+        if mass <= 0. || stiffness <= 0. || damping < 0. {
+            AnimationAmountCalc::default()
+        } else {
+            let mode = EAmountMode::Spring;
+            let (omega0, zeta) = spring_omega_zeta(mass, stiffness, damping);
+            let duration = spring_settling_duration(mass, stiffness, damping, velocity);
+            Self {
+                mode,
+                param: AmountParam(omega0, zeta, velocity, duration),
+                call: EAmountMode::get_transform_amount_call(mode),
+                calc_call: Self::calc_param,
+                linear_stops: Vec::new(),
+            }
+        }
+    }
+    /// 创建一个分段线性模式的进度计算器 (CSS linear())
+    ///
+    /// * [stops] - 停靠点 (输出进度, 输入进度), 输入进度以 [0, 1] 表示
+    ///
+    /// 缺省的输入进度按 CSS 规则补全: 首尾缺省为 0 和 1, 小于之前输入的取之前的最大输入, 中间连续缺省的在两侧之间均分
+    ///
+    pub fn from_linear_stops(stops: &[(KeyFrameCurveValue, Option<KeyFrameCurveValue>)]) -> Self {
+        let count = stops.len();
+        if count < 2 {
+            return AnimationAmountCalc::default();
+        }
+
+        let mut inputs: Vec<Option<KeyFrameCurveValue>> = stops.iter().map(|(_, input)| *input).collect();
+        if inputs[0].is_none() {
+            inputs[0] = Some(0.);
+        }
+        if inputs[count - 1].is_none() {
+            inputs[count - 1] = Some(1.);
+        }
+
+        let mut max_input = KeyFrameCurveValue::MIN;
+        for input in inputs.iter_mut().flatten() {
+            max_input = KeyFrameCurveValue::max(max_input, *input);
+            *input = max_input;
+        }
+
+        let mut params = Vec::with_capacity(count * 2);
+        let mut index = 0;
+        while index < count {
+            if let Some(input) = inputs[index] {
+                params.push(input);
+                index += 1;
+            } else {
+                let start = params[index - 1];
+                let mut end_index = index;
+                while inputs[end_index].is_none() {
+                    end_index += 1;
+                }
+                let end = inputs[end_index].unwrap();
+                let gaps = (end_index - index + 1) as KeyFrameCurveValue;
+                for i in index..end_index {
+                    params.push(start + (end - start) * (i - index + 1) as KeyFrameCurveValue / gaps);
+                }
+                index = end_index;
+            }
+        }
+        params.extend(stops.iter().map(|(output, _)| *output));
+
+        let mode = EAmountMode::Linear;
+        Self {
+            mode,
+            param: AmountParam::default(),
+            call: EAmountMode::get_transform_amount_call(mode),
+            calc_call: Self::calc_linear_stops,
+            linear_stops: params,
+        }
+    }
+    /// 弹簧模式的稳定时长 (秒), 其他模式返回 None
+    pub fn spring_duration(&self) -> Option<KeyFrameCurveValue> {
+        match self.mode {
+            EAmountMode::Spring => Some(self.param.3),
+            _ => None,
         }
     }
     /// 计算进度
     pub fn calc(&self, amount: KeyFrameCurveValue) -> KeyFrameCurveValue {
+        let call = &self.calc_call;
+        call(self, amount)
+    }
+    fn calc_param(&self, amount: KeyFrameCurveValue) -> KeyFrameCurveValue {
         let call = &self.call;
         call(amount, &self.param)
     }
+    fn calc_linear_stops(&self, amount: KeyFrameCurveValue) -> KeyFrameCurveValue {
+        EAmountMode::calc_amount_linear(amount, &self.linear_stops)
+    }
 }
\ No newline at end of file
@@ -34,3 +34,58 @@ pub fn cubic_bezier(x1: KeyFrameCurveValue, y1: KeyFrameCurveValue, x2: KeyFrame
 
     _3 * KeyFrameCurveValue::powi(_1 - refined_t, 2) * refined_t * _y1 + _3 * (_1 - refined_t) * KeyFrameCurveValue::powi(refined_t, 2) * _y2 + KeyFrameCurveValue::powi(refined_t, 3)
 }
+
+/// 求解 2D 三次贝塞尔曲线 P0 = (0, y0), P1, P2, P3 = (1, y3) 在 x 处的 y 值
+/// 控制点 P1, P2 为 [x, y], x 需在 [0, 1] 内以保证曲线在 x 方向单调
+pub fn cubic_bezier_value(y0: KeyFrameCurveValue, p1: [KeyFrameCurveValue; 2], p2: [KeyFrameCurveValue; 2], y3: KeyFrameCurveValue, x: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    let [x1, y1] = p1;
+    let [x2, y2] = p2;
+    let bezier = |p0: KeyFrameCurveValue, p1: KeyFrameCurveValue, p2: KeyFrameCurveValue, p3: KeyFrameCurveValue, t: KeyFrameCurveValue| -> KeyFrameCurveValue {
+        let s = 1. - t;
+        s * s * s * p0 + 3. * s * s * t * p1 + 3. * s * t * t * p2 + t * t * t * p3
+    };
+
+    let x = KeyFrameCurveValue::clamp(x, 0., 1.);
+    let mut low = 0.;
+    let mut high = 1.;
+    let mut t = x;
+
+    // Newton 迭代, 越界或斜率过小时退化为二分
+    for _ in 0..16 {
+        let delta = bezier(0., x1, x2, 1., t) - x;
+        if delta.abs() < 1e-6 {
+            break;
+        }
+        if delta > 0. {
+            high = t;
+        } else {
+            low = t;
+        }
+
+        let s = 1. - t;
+        let slope = 3. * s * s * x1 + 6. * s * t * (x2 - x1) + 3. * t * t * (1. - x2);
+        let next = if slope.abs() > 1e-6 { t - delta / slope } else { -1. };
+        t = if next > low && next < high { next } else { (low + high) * 0.5 };
+    }
+
+    bezier(y0, y1, y2, y3, t)
+}
+
+/// CubicBezier 缓动在起点 (end 为 false) 或终点处的斜率, 与 CSS cubic-bezier 区间外的外插规则一致
+pub fn cubic_bezier_edge_slope(x1: KeyFrameCurveValue, y1: KeyFrameCurveValue, x2: KeyFrameCurveValue, y2: KeyFrameCurveValue, end: bool) -> KeyFrameCurveValue {
+    if !end {
+        if x1 > 0. {
+            y1 / x1
+        } else if y1 == 0. && x2 > 0. {
+            y2 / x2
+        } else {
+            0.
+        }
+    } else if x2 < 1. {
+        (y2 - 1.) / (x2 - 1.)
+    } else if y2 == 1. && x1 < 1. {
+        (y1 - 1.) / (x1 - 1.)
+    } else {
+        0.
+    }
+}
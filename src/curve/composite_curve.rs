@@ -0,0 +1,203 @@
+//!
+//! 组合曲线 - 组合多条 FrameCurve 子曲线 (相加 / 相乘 / 依次播放 / 时间重映射)
+use std::{fmt::Debug, ops::Mul};
+
+use crate::amount::AnimationAmountCalc;
+
+use super::{curves::FrameCurve, frame::{FrameDataValue, KeyFrameCurveValue}, FramePerSecond};
+
+/// 组合曲线的组合方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ECompositeMode {
+    /// 子曲线数值相加 (FrameDataValue::append)
+    Sum,
+    /// 子曲线数值相乘
+    Product,
+    /// 子曲线依次首尾相接播放
+    Sequence,
+    /// 时间曲线的输出 (秒) 作为子曲线的时间输入
+    TimeRemap,
+}
+
+/// 组合曲线, 子曲线按各自的设计帧率与区间外求值方式计算
+pub struct CompositeCurve<T: FrameDataValue> {
+    /// 设计每秒多少帧
+    pub design_frame_per_second: FramePerSecond,
+    /// 动画目标数据的起始值, 没有子曲线时作为求值结果, 未设置时为 T::default()
+    pub value_offset: Option<T>,
+    /// 子曲线
+    pub children: Vec<FrameCurve<T>>,
+    /// TimeRemap 的时间曲线, 输出为子曲线的时间 (秒)
+    pub time_remap: Option<FrameCurve<KeyFrameCurveValue>>,
+    /// 动画帧数
+    pub frame_number: KeyFrameCurveValue,
+    /// 组合方式
+    mode: ECompositeMode,
+    call: fn(&Self, KeyFrameCurveValue, &AnimationAmountCalc) -> T,
+}
+
+impl<T: Debug + FrameDataValue> Debug for CompositeCurve<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompositeCurve")
+            .field("mode", &self.mode)
+            .field("design_frame_per_second", &self.design_frame_per_second)
+            .field("value_offset", &self.value_offset)
+            .field("children", &self.children)
+            .field("time_remap", &self.time_remap)
+            .field("frame_number", &self.frame_number)
+            .finish()
+    }
+}
+
+impl<T: FrameDataValue + Default> CompositeCurve<T> {
+    /// 组合曲线 - 子曲线数值相加, 子曲线使用 add_child 添加
+    ///
+    pub fn sum(design_frame_per_second: FramePerSecond) -> Self {
+        Self::_new(design_frame_per_second, ECompositeMode::Sum, interplate_composite_sum::<T>)
+    }
+    /// 组合曲线 - 子曲线依次首尾相接播放, 子曲线使用 add_child 添加
+    ///
+    /// 超出首尾时由首尾子曲线按各自的区间外求值方式计算, 如末尾子曲线设置 Loop 可实现 "开场 + 循环主体"
+    ///
+    pub fn sequence(design_frame_per_second: FramePerSecond) -> Self {
+        Self::_new(design_frame_per_second, ECompositeMode::Sequence, interplate_composite_sequence::<T>)
+    }
+    /// 组合曲线 - 时间重映射
+    ///
+    /// * [time_remap] - 时间曲线, 输入为时间 (秒), 输出作为 curve 的时间 (秒)
+    /// * [curve] - 被重映射的曲线
+    ///
+    pub fn remap(design_frame_per_second: FramePerSecond, time_remap: FrameCurve<KeyFrameCurveValue>, curve: FrameCurve<T>) -> Self {
+        let mut result = Self::_new(design_frame_per_second, ECompositeMode::TimeRemap, interplate_composite_remap::<T>);
+        result.time_remap = Some(time_remap);
+        result.add_child(curve);
+        result
+    }
+    fn _new(design_frame_per_second: FramePerSecond, mode: ECompositeMode, call: fn(&Self, KeyFrameCurveValue, &AnimationAmountCalc) -> T) -> Self {
+        Self {
+            design_frame_per_second,
+            value_offset: None,
+            children: vec![],
+            time_remap: None,
+            frame_number: 0.,
+            mode,
+            call,
+        }
+    }
+}
+
+impl<T: FrameDataValue + Default + Mul<Output = T>> CompositeCurve<T> {
+    /// 组合曲线 - 子曲线数值相乘, 子曲线使用 add_child 添加
+    ///
+    pub fn product(design_frame_per_second: FramePerSecond) -> Self {
+        Self::_new(design_frame_per_second, ECompositeMode::Product, interplate_composite_product::<T>)
+    }
+}
+
+impl<T: FrameDataValue> CompositeCurve<T> {
+    pub fn mode(&self) -> ECompositeMode {
+        self.mode
+    }
+    /// 添加子曲线, 并更新组合曲线的帧数
+    ///
+    /// Sum / Product 取子曲线中最长的时长, Sequence 取子曲线时长之和, TimeRemap 取时间曲线的时长
+    ///
+    pub fn add_child(&mut self, child: FrameCurve<T>) {
+        self.children.push(child);
+
+        let duration = match self.mode {
+            ECompositeMode::Sum | ECompositeMode::Product => {
+                self.children.iter().map(child_duration).fold(0., KeyFrameCurveValue::max)
+            },
+            ECompositeMode::Sequence => {
+                self.children.iter().map(child_duration).sum()
+            },
+            ECompositeMode::TimeRemap => {
+                self.time_remap.as_ref().map_or(0., child_duration)
+            },
+        };
+
+        self.frame_number = duration * self.design_frame_per_second as KeyFrameCurveValue;
+    }
+    pub fn size(&self) -> usize {
+        1 + 2 + 4 + 8
+        + self.children.iter().map(|child| child.size()).sum::<usize>()
+        + self.time_remap.as_ref().map_or(0, |curve| curve.size())
+    }
+    /// 求值
+    ///
+    /// * [target_frame] - 目标时间 (秒)
+    ///
+    pub fn interple(&self, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+        (self.call)(self, target_frame, amountcalc)
+    }
+}
+
+fn interplate_composite_sum<T: FrameDataValue + Default>(curve: &CompositeCurve<T>, seconds: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let Some(first) = curve.children.first() else {
+        return empty_value(curve);
+    };
+    let mut result = first.interple(seconds, amountcalc);
+    for child in curve.children.iter().skip(1) {
+        result = result.append(&child.interple(seconds, amountcalc), 1.);
+    }
+    result
+}
+
+fn interplate_composite_product<T: FrameDataValue + Default + Mul<Output = T>>(curve: &CompositeCurve<T>, seconds: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let Some(first) = curve.children.first() else {
+        return empty_value(curve);
+    };
+    let mut result = first.interple(seconds, amountcalc);
+    for child in curve.children.iter().skip(1) {
+        result = result * child.interple(seconds, amountcalc);
+    }
+    result
+}
+
+fn interplate_composite_sequence<T: FrameDataValue + Default>(curve: &CompositeCurve<T>, seconds: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let Some((last, children)) = curve.children.split_last() else {
+        return empty_value(curve);
+    };
+
+    // 超出首尾时由首尾子曲线按各自的区间外求值方式计算
+    let mut start = 0.;
+    for child in children {
+        let duration = child_duration(child);
+        if seconds < start + duration {
+            let local = child_start(child) + (seconds - start);
+            return child.interple(local, amountcalc);
+        }
+        start += duration;
+    }
+
+    let local = child_start(last) + (seconds - start);
+    last.interple(local, amountcalc)
+}
+
+fn interplate_composite_remap<T: FrameDataValue + Default>(curve: &CompositeCurve<T>, seconds: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let Some(child) = curve.children.first() else {
+        return empty_value(curve);
+    };
+    let seconds = curve.time_remap.as_ref().map_or(seconds, |time_remap| time_remap.interple(seconds, amountcalc));
+    child.interple(seconds, amountcalc)
+}
+
+/// 没有子曲线时的数值 - 动画目标数据的起始值, 未设置时为 T::default()
+fn empty_value<T: FrameDataValue + Default>(curve: &CompositeCurve<T>) -> T {
+    curve.value_offset.clone().unwrap_or_default()
+}
+
+/// 子曲线时长 (秒)
+fn child_duration<T: FrameDataValue>(child: &FrameCurve<T>) -> KeyFrameCurveValue {
+    KeyFrameCurveValue::max(child.frame_number, 0.) / child.design_frame_per_second as KeyFrameCurveValue
+}
+
+/// 子曲线起始时间 (秒)
+fn child_start<T: FrameDataValue>(child: &FrameCurve<T>) -> KeyFrameCurveValue {
+    if child.frame_number > 0. {
+        child.min_frame / child.design_frame_per_second as KeyFrameCurveValue
+    } else {
+        0.
+    }
+}
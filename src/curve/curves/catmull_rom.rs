@@ -0,0 +1,88 @@
+use crate::{amount::AnimationAmountCalc, curve::{curves::frame_segment_amount, frame::{FrameDataValue, FrameValueDistance, KeyFrameCurveValue}}};
+
+use super::FrameCurve;
+
+/// Catmull-Rom 样条的参数化方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ECatmullRomMode {
+    /// 均匀参数化 (alpha = 0)
+    Uniform,
+    /// 向心参数化 (alpha = 0.5), 不产生尖点与自交
+    Centripetal,
+    /// 弦长参数化 (alpha = 1)
+    Chordal,
+}
+
+impl ECatmullRomMode {
+    pub fn alpha(&self) -> KeyFrameCurveValue {
+        match self {
+            ECatmullRomMode::Uniform        => 0.0,
+            ECatmullRomMode::Centripetal    => 0.5,
+            ECatmullRomMode::Chordal        => 1.0,
+        }
+    }
+}
+
+pub fn interplate_catmull_rom<T: FrameDataValue + FrameValueDistance>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let (pre, next, amount) = frame_segment_amount(&curve.frames, target_frame, amountcalc);
+
+    let value1 = &curve.values[pre];
+    let value2 = &curve.values[next];
+
+    if pre == next {
+        return value1.clone();
+    }
+
+    // 首尾缺少的控制点由相邻关键帧镜像得到
+    let value0 = if pre > 0 {
+        curve.values[pre - 1].clone()
+    } else {
+        value1.interpolate(value2, -1.0)
+    };
+    let value3 = if next + 1 < curve.values.len() {
+        curve.values[next + 1].clone()
+    } else {
+        value2.interpolate(value1, -1.0)
+    };
+
+    catmull_rom(&value0, value1, value2, &value3, amount, curve.catmull_rom_mode.alpha())
+}
+
+/// Barry-Goldman 金字塔形式计算 Catmull-Rom 样条, 仅依赖 FrameDataValue::interpolate
+fn catmull_rom<T: FrameDataValue + FrameValueDistance>(value0: &T, value1: &T, value2: &T, value3: &T, amount: KeyFrameCurveValue, alpha: KeyFrameCurveValue) -> T {
+    let knot = |a: &T, b: &T| -> KeyFrameCurveValue {
+        if alpha == 0.0 {
+            1.0
+        } else {
+            a.distance(b).powf(alpha)
+        }
+    };
+
+    let delta12 = knot(value1, value2);
+    if delta12 <= KeyFrameCurveValue::EPSILON {
+        return value1.interpolate(value2, amount);
+    }
+
+    let mut delta01 = knot(value0, value1);
+    if delta01 <= KeyFrameCurveValue::EPSILON {
+        delta01 = delta12;
+    }
+    let mut delta23 = knot(value2, value3);
+    if delta23 <= KeyFrameCurveValue::EPSILON {
+        delta23 = delta12;
+    }
+
+    let t1 = delta01;
+    let t2 = t1 + delta12;
+    let t3 = t2 + delta23;
+    let t = t1 + amount * delta12;
+
+    let a1 = value0.interpolate(value1, t / delta01);
+    let a2 = value1.interpolate(value2, amount);
+    let a3 = value2.interpolate(value3, (t - t2) / delta23);
+
+    let b1 = a1.interpolate(&a2, t / t2);
+    let b2 = a2.interpolate(&a3, (t - t1) / (t3 - t1));
+
+    b1.interpolate(&b2, amount)
+}
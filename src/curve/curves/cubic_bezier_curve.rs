@@ -1,4 +1,4 @@
-use crate::{curve::{frame_curve::FrameCurve, frame::{FrameDataValue, KeyFrameCurveValue}}, bezier::cubic_bezier, amount::AnimationAmountCalc};
+use crate::{curve::{curves::EdgeSlope, frame_curve::FrameCurve, frame::{FrameDataValue, KeyFrameCurveValue}}, bezier::{cubic_bezier, cubic_bezier_edge_slope}, amount::AnimationAmountCalc};
 
 
 pub fn interplate_cubebezier<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, _amountcalc: &AnimationAmountCalc) -> T {
@@ -17,4 +17,11 @@ pub fn interplate_cubebezier<T: FrameDataValue>(curve: &FrameCurve<T>, target_fr
     );
 
     curve.value_offset.as_ref().unwrap().append(curve.value_scalar.as_ref().unwrap(), amount)
+}
+
+/// 线性外插 - CubicBezier 在端点处的斜率
+pub fn edge_slope_cubebezier<T: FrameDataValue>(curve: &FrameCurve<T>, end: bool) -> EdgeSlope<T> {
+    let args = &curve.cubic_bezier_args;
+    let slope = cubic_bezier_edge_slope(args[0], args[1], args[2], args[3], end);
+    Some((curve.value_scalar.clone()?, slope / curve.frame_number))
 }
\ No newline at end of file
@@ -1,11 +1,12 @@
-use crate::{amount::AnimationAmountCalc, curve::{curves::FrameCurve, frame::{FrameDataValue, KeyFrameCurveValue}, FrameIndex}};
+use crate::{amount::AnimationAmountCalc, curve::{curves::FrameCurve, frame::{FrameDataValue, KeyFrameCurveValue}}};
 
-use super::get_pre_next_frame_index;
+use super::{frame_segment_amount, EdgeSlope};
 
 
 pub fn interplate_cubic_splice<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
 
-    let (pre, next, amount, frame_delta) = _interplate_cubic_splice_amount(&curve.frames, target_frame, amountcalc, curve.design_frame_per_second as KeyFrameCurveValue);
+    let (pre, next, amount) = frame_segment_amount(&curve.frames, target_frame, amountcalc);
+    let frame_delta = (curve.frames[next] - curve.frames[pre]) / curve.design_frame_per_second as KeyFrameCurveValue;
 
     let value1 = curve.cubic_spline_values[pre].value();
     let value2 = curve.cubic_spline_values[next].value();
@@ -16,28 +17,13 @@ pub fn interplate_cubic_splice<T: FrameDataValue>(curve: &FrameCurve<T>, target_
     T::hermite(value1, tangent1, value2, tangent2, amount, frame_delta)
 }
 
-fn _interplate_cubic_splice_amount(frames: &Vec<FrameIndex>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc, design_frame_per_second: KeyFrameCurveValue) -> (usize, usize, KeyFrameCurveValue, KeyFrameCurveValue) {
-    let (pre, next) = get_pre_next_frame_index(frames, target_frame);
-
-    let frame1 = frames[pre];
-    let frame2 = frames[next];
-
-    let mut frame_delta = frame2 as KeyFrameCurveValue - frame1 as KeyFrameCurveValue;
-
-    let amount = if frame1 == frame2 {
-        0.0
+/// 线性外插 - 起始关键帧的出切线 / 结束关键帧的入切线, 切线单位为 数值/秒
+pub fn edge_slope_cubic_splice<T: FrameDataValue>(curve: &FrameCurve<T>, end: bool) -> EdgeSlope<T> {
+    let tangent = if end {
+        curve.cubic_spline_values.last()?.intangent()
     } else {
-        KeyFrameCurveValue::clamp(
-            amountcalc.calc(
-            (target_frame - frame1 as KeyFrameCurveValue)
-                / frame_delta
-            ),
-            0.,
-            1.,
-        )
+        curve.cubic_spline_values.first()?.outtangent()
     };
 
-    frame_delta = frame_delta / design_frame_per_second;
-
-    return (pre, next, amount, frame_delta);
+    Some((tangent.clone(), 1. / curve.design_frame_per_second as KeyFrameCurveValue))
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{curve::{frame_curve::FrameCurve, frame::{FrameDataValue, KeyFrameCurveValue}}, amount::AnimationAmountCalc};
+use crate::{curve::{curves::EdgeSlope, frame_curve::FrameCurve, frame::{FrameDataValue, KeyFrameCurveValue}}, amount::AnimationAmountCalc};
 
 
 
@@ -17,3 +17,11 @@ pub fn interplate_easing<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame:
 
     curve.value_offset.as_ref().unwrap().append(curve.value_scalar.as_ref().unwrap(), amount)
 }
+
+/// 线性外插 - 缓动函数没有解析导数, 在端点处对缓动函数取差分
+pub fn edge_slope_easing<T: FrameDataValue>(curve: &FrameCurve<T>, end: bool) -> EdgeSlope<T> {
+    let step = 0.0001;
+    let call = &curve.easing;
+    let slope = if end { (call(1.) - call(1. - step)) / step } else { (call(step) - call(0.)) / step };
+    Some((curve.value_scalar.clone()?, slope / curve.frame_number))
+}
@@ -0,0 +1,26 @@
+use crate::{amount::AnimationAmountCalc, bezier::{cubic_bezier, cubic_bezier_edge_slope}, curve::{curves::frame_segment_amount, frame::{FrameDataValue, KeyFrameCurveValue}}};
+
+use super::{edge_segment, segment_slope, EdgeSlope, FrameCurve};
+
+
+pub fn interplate_frame_bezier<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let (pre, next, amount) = frame_segment_amount(&curve.frames, target_frame, amountcalc);
+
+    let value1 = &curve.values[pre];
+    let value2 = &curve.values[next];
+
+    // 每段使用起始关键帧的 bezier 控制柄
+    let handles = &curve.bezier_handles[pre];
+    let amount = cubic_bezier(handles[0], handles[1], handles[2], handles[3], amount);
+
+    value1.interpolate(value2, amount)
+}
+
+/// 线性外插 - 端点所在段的斜率乘以该段控制柄在端点处的斜率
+pub fn edge_slope_frame_bezier<T: FrameDataValue>(curve: &FrameCurve<T>, end: bool) -> EdgeSlope<T> {
+    let (pre, next) = edge_segment(&curve.frames, end)?;
+    let (delta, rate) = segment_slope(&curve.values[pre], &curve.values[next], curve.frames[next] - curve.frames[pre])?;
+
+    let handles = &curve.bezier_handles[pre];
+    Some((delta, rate * cubic_bezier_edge_slope(handles[0], handles[1], handles[2], handles[3], end)))
+}
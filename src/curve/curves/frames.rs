@@ -1,19 +1,46 @@
-use crate::{amount::AnimationAmountCalc, curve::{curves::get_pre_next_frame_index, frame::{FrameDataValue, KeyFrameCurveValue}, FrameIndex}};
+use crate::{amount::AnimationAmountCalc, curve::{curves::frame_segment_amount, frame::{FrameDataValue, KeyFrameCurveValue}}};
 
-use super::FrameCurve;
+use super::{edge_segment, segment_slope, EdgeSlope, FrameCurve};
 
 
 
 pub fn interplate_frame_values<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
-    let (pre, next, amount) = _interplate_frame_values_amount(&curve.frames, target_frame, amountcalc);
+    let (pre, next, amount) = frame_segment_amount(&curve.frames, target_frame, amountcalc);
     let value1 = curve.values.get(pre).unwrap();
     let value2 = curve.values.get(next).unwrap();
-    value1.interpolate(&value2, amount)
+    value1.interpolate(value2, amount)
 }
 
+/// 线性外插 - 端点所在线性段的斜率
+pub fn edge_slope_frame_values<T: FrameDataValue>(curve: &FrameCurve<T>, end: bool) -> EdgeSlope<T> {
+    let (pre, next) = edge_segment(&curve.frames, end)?;
+    segment_slope(&curve.values[pre], &curve.values[next], curve.frames[next] - curve.frames[pre])
+}
+
+
+/// 步进关键帧在两关键帧之间的取值方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EFrameStepMode {
+    /// 保持前一关键帧数值 (GLTF STEP)
+    Previous,
+    /// 离开关键帧后即取下一关键帧数值
+    Next,
+    /// 取较近的关键帧数值
+    Nearest,
+}
+
+impl EFrameStepMode {
+    pub fn get_interplate_call<T: FrameDataValue>(mode: EFrameStepMode) -> fn(&FrameCurve<T>, KeyFrameCurveValue, &AnimationAmountCalc) -> T {
+        match mode {
+            EFrameStepMode::Previous    => interplate_frame_values_step_previous::<T>,
+            EFrameStepMode::Next        => interplate_frame_values_step_next::<T>,
+            EFrameStepMode::Nearest     => interplate_frame_values_step::<T>,
+        }
+    }
+}
 
 pub fn interplate_frame_values_step<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
-    let (pre, next, amount) = _interplate_frame_values_amount(&curve.frames, target_frame, amountcalc);
+    let (pre, next, amount) = frame_segment_amount(&curve.frames, target_frame, amountcalc);
     let value1 = curve.values.get(pre).unwrap();
     let value2 = curve.values.get(next).unwrap();
 
@@ -24,24 +51,22 @@ pub fn interplate_frame_values_step<T: FrameDataValue>(curve: &FrameCurve<T>, ta
     }
 }
 
-fn _interplate_frame_values_amount(frames: &Vec<FrameIndex>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> (usize, usize, KeyFrameCurveValue) {
-    let (pre, next) = get_pre_next_frame_index(frames, target_frame);
-    let frame1 = frames[pre];
+pub fn interplate_frame_values_step_previous<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let (pre, next, amount) = frame_segment_amount(&curve.frames, target_frame, amountcalc);
+
+    if amount < 1.0 {
+        curve.values[pre].clone()
+    } else {
+        curve.values[next].clone()
+    }
+}
 
-    let frame2 = frames[next];
+pub fn interplate_frame_values_step_next<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let (pre, next, amount) = frame_segment_amount(&curve.frames, target_frame, amountcalc);
 
-    let amount = if frame1 == frame2 {
-        0.0
+    if amount > 0.0 {
+        curve.values[next].clone()
     } else {
-        KeyFrameCurveValue::clamp(
-            amountcalc.calc(
-                (target_frame - frame1 as KeyFrameCurveValue)
-                / (frame2 as KeyFrameCurveValue - frame1 as KeyFrameCurveValue)
-            ),
-            0.,
-            1.,
-        )
-    };
-
-    return (pre, next, amount); 
+        curve.values[pre].clone()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+use crate::{amount::AnimationAmountCalc, curve::{curves::get_pre_next_frame_index, frame::{FrameDataValue, FrameValueAlpha, KeyFrameCurveValue}}};
+
+use super::FrameCurve;
+
+/// 渐变的关键帧混合方式 (类 Unity GradientMode)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EGradientMode {
+    /// 相邻关键帧之间插值
+    Blend,
+    /// 取时间不小于目标时间的第一个关键帧, 无插值
+    Fixed,
+}
+
+pub fn interplate_gradient<T: FrameDataValue + FrameValueAlpha>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let time = if curve.frame_number <= 0. {
+        0.
+    } else {
+        KeyFrameCurveValue::clamp(
+            amountcalc.calc(
+                (target_frame - curve.min_frame)
+                / curve.frame_number
+            ),
+            0.,
+            1.,
+        )
+    };
+
+    // 没有颜色关键帧时为不透明白色, 没有透明度关键帧时使用颜色自身的透明度
+    let mode = curve.gradient_mode;
+    let color = if curve.values.is_empty() {
+        T::white()
+    } else {
+        _gradient_key(&curve.frames, &curve.values, time, mode, |a, b, amount| a.interpolate(b, amount))
+    };
+
+    if curve.gradient_alphas.is_empty() {
+        color
+    } else {
+        let alpha = _gradient_key(&curve.gradient_alpha_frames, &curve.gradient_alphas, time, mode, |a, b, amount| a + (b - a) * amount);
+        color.with_alpha(alpha)
+    }
+}
+
+fn _gradient_key<V: Clone>(times: &[KeyFrameCurveValue], keys: &[V], time: KeyFrameCurveValue, mode: EGradientMode, lerp: impl Fn(&V, &V, KeyFrameCurveValue) -> V) -> V {
+    let (pre, next) = get_pre_next_frame_index(times, time);
+
+    let time1 = times[pre];
+    let time2 = times[next];
+
+    match mode {
+        EGradientMode::Fixed => {
+            if time <= time1 {
+                keys[pre].clone()
+            } else {
+                keys[next].clone()
+            }
+        },
+        EGradientMode::Blend => {
+            if time1 == time2 {
+                keys[pre].clone()
+            } else {
+                lerp(&keys[pre], &keys[next], (time - time1) / (time2 - time1))
+            }
+        },
+    }
+}
@@ -1,14 +1,74 @@
-use crate::{amount::AnimationAmountCalc, curve::{curves::get_pre_next_frame_index, frame::{CurveFrameValue, FrameDataValue, KeyFrameCurveValue}, FrameIndex}, hermite};
+use crate::{amount::AnimationAmountCalc, curve::{curves::get_pre_next_frame_index, frame::{CurveFrameValue, FrameDataValue, KeyFrameCurveValue}}, hermite, bezier::cubic_bezier_value};
 
-use super::FrameCurve;
+use super::{edge_segment, EdgeSlope, FrameCurve};
 
 
+/// MinMaxCurve 模式 (类 Unity ParticleSystem.MinMaxCurveMode)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EMinMaxCurveMode {
+    /// 常数
+    Constant,
+    /// 曲线 * 曲线倍数
+    Curve,
+    /// 在两个常数之间随机
+    TwoConstants,
+    /// 在两条曲线之间随机, 结果 * 曲线倍数
+    TwoCurves,
+}
+
+/// Curve / TwoCurves 模式 - 最大值曲线
 pub fn interplate_minmaxcurve<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
     let amount = _interplate_minmaxcurve_amount(&curve.frames, target_frame, &curve.minmax_curve_values, amountcalc);
+    let amount = amount * curve.minmax_curve_multiplier;
     curve.value_offset.as_ref().unwrap().append(curve.value_scalar.as_ref().unwrap(), amount)
 }
 
-fn _interplate_minmaxcurve_amount(frames: &Vec<FrameIndex>, target_frame: KeyFrameCurveValue, minmax_curve_values: &Vec<CurveFrameValue<KeyFrameCurveValue>>, amountcalc: &AnimationAmountCalc) -> KeyFrameCurveValue {
+/// TwoCurves 模式 - 最小值曲线
+pub fn interplate_minmaxcurve_min<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let amount = if curve.minmax_curve_min_values.is_empty() {
+        0.
+    } else {
+        _interplate_minmaxcurve_amount(&curve.minmax_curve_min_frames, target_frame, &curve.minmax_curve_min_values, amountcalc)
+    };
+    let amount = amount * curve.minmax_curve_multiplier;
+    curve.value_offset.as_ref().unwrap().append(curve.value_scalar.as_ref().unwrap(), amount)
+}
+
+/// Constant / TwoConstants 模式 - 最大值常数
+pub fn interplate_minmax_constant<T: FrameDataValue>(curve: &FrameCurve<T>, _target_frame: KeyFrameCurveValue, _amountcalc: &AnimationAmountCalc) -> T {
+    curve.value_offset.as_ref().unwrap().append(curve.value_scalar.as_ref().unwrap(), curve.minmax_constants[1])
+}
+
+/// TwoConstants 模式 - 最小值常数
+pub fn interplate_minmax_constant_min<T: FrameDataValue>(curve: &FrameCurve<T>, _target_frame: KeyFrameCurveValue, _amountcalc: &AnimationAmountCalc) -> T {
+    curve.value_offset.as_ref().unwrap().append(curve.value_scalar.as_ref().unwrap(), curve.minmax_constants[0])
+}
+
+/// 线性外插 - Curve / TwoCurves 模式最大值曲线端点关键帧的切线
+pub fn edge_slope_minmaxcurve<T: FrameDataValue>(curve: &FrameCurve<T>, end: bool) -> EdgeSlope<T> {
+    let slope = _minmaxcurve_edge_slope(&curve.frames, &curve.minmax_curve_values, end)?;
+    Some((curve.value_scalar.clone()?, slope * curve.minmax_curve_multiplier))
+}
+
+/// 线性外插 - TwoCurves 模式最小值曲线端点关键帧的切线
+pub fn edge_slope_minmaxcurve_min<T: FrameDataValue>(curve: &FrameCurve<T>, end: bool) -> EdgeSlope<T> {
+    let slope = _minmaxcurve_edge_slope(&curve.minmax_curve_min_frames, &curve.minmax_curve_min_values, end)?;
+    Some((curve.value_scalar.clone()?, slope * curve.minmax_curve_multiplier))
+}
+
+/// 切线单位为 数值/区间, 除以端点所在区间的帧数得到每帧的斜率
+fn _minmaxcurve_edge_slope(frames: &[KeyFrameCurveValue], minmax_curve_values: &[CurveFrameValue<KeyFrameCurveValue>], end: bool) -> Option<KeyFrameCurveValue> {
+    let (pre, next) = edge_segment(frames, end)?;
+    let frame_delta = frames[next] - frames[pre];
+    if frame_delta <= KeyFrameCurveValue::EPSILON {
+        return None;
+    }
+
+    let tangent = if end { minmax_curve_values[next].intangent() } else { minmax_curve_values[pre].outtangent() };
+    Some(*tangent / frame_delta)
+}
+
+fn _interplate_minmaxcurve_amount(frames: &[KeyFrameCurveValue], target_frame: KeyFrameCurveValue, minmax_curve_values: &[CurveFrameValue<KeyFrameCurveValue>], amountcalc: &AnimationAmountCalc) -> KeyFrameCurveValue {
     let (pre, next) = get_pre_next_frame_index(frames, target_frame);
 
     let frame1 = frames[pre];
@@ -25,14 +85,29 @@ fn _interplate_minmaxcurve_amount(frames: &Vec<FrameIndex>, target_frame: KeyFra
     } else {
         KeyFrameCurveValue::clamp(
             amountcalc.calc(
-            (target_frame - frame1 as KeyFrameCurveValue)
-                / (frame2 as KeyFrameCurveValue - frame1 as KeyFrameCurveValue)
+            (target_frame - frame1)
+                / (frame2 - frame1)
             ),
             0.,
             1.,
         )
     };
 
-    let amount = hermite::hermite(*value1, *tangent1, *value2, *tangent2, amount);
-    return amount;
+    let outweighted = minmax_curve_values[pre].weighted_mode().has_out();
+    let inweighted = minmax_curve_values[next].weighted_mode().has_in();
+
+    if outweighted || inweighted {
+        // 带权重的切线 - 按 (时间, 数值) 二维贝塞尔曲线求值
+        let outweight = minmax_curve_values[pre].outweight();
+        let inweight = minmax_curve_values[next].inweight();
+        cubic_bezier_value(
+            *value1,
+            [outweight, *value1 + *tangent1 * outweight],
+            [1. - inweight, *value2 - *tangent2 * inweight],
+            *value2,
+            amount,
+        )
+    } else {
+        hermite::hermite(*value1, *tangent1, *value2, *tangent2, amount)
+    }
 }
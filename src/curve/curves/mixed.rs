@@ -0,0 +1,62 @@
+use crate::{amount::AnimationAmountCalc, bezier::{cubic_bezier, cubic_bezier_edge_slope}, curve::{curves::frame_segment_amount, frame::{FrameDataValue, KeyFrameCurveValue}}};
+
+use super::{edge_segment, edge_slope_cubic_splice, segment_slope, EdgeSlope, FrameCurve};
+
+/// 关键帧到下一关键帧的插值方式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EFrameInterpolation {
+    /// 保持当前关键帧数值直到下一关键帧
+    Constant,
+    /// 线性插值
+    Linear,
+    /// Hermite 插值, 使用关键帧的 out/in 切线 (GLTF CubicSpline)
+    Hermite,
+    /// 线性插值, 进度由 CubicBezier (x1, y1, x2, y2) 控制
+    Bezier(KeyFrameCurveValue, KeyFrameCurveValue, KeyFrameCurveValue, KeyFrameCurveValue),
+}
+
+pub fn interplate_mixed<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let (pre, next, amount) = frame_segment_amount(&curve.frames, target_frame, amountcalc);
+    let frame_delta = (curve.frames[next] - curve.frames[pre]) / curve.design_frame_per_second as KeyFrameCurveValue;
+
+    let value1 = curve.cubic_spline_values[pre].value();
+    let value2 = curve.cubic_spline_values[next].value();
+
+    // 插值方式与关键帧不对应时 (直接修改了公开数组) 使用 Hermite
+    let interpolation = curve.interpolations.get(pre).cloned().unwrap_or(EFrameInterpolation::Hermite);
+    match interpolation {
+        EFrameInterpolation::Constant => {
+            value1.clone()
+        },
+        EFrameInterpolation::Linear => {
+            value1.interpolate(value2, amount)
+        },
+        EFrameInterpolation::Hermite => {
+            let tangent1 = curve.cubic_spline_values[pre].outtangent();
+            let tangent2 = curve.cubic_spline_values[next].intangent();
+            T::hermite(value1, tangent1, value2, tangent2, amount, frame_delta)
+        },
+        EFrameInterpolation::Bezier(x1, y1, x2, y2) => {
+            value1.interpolate(value2, cubic_bezier(x1, y1, x2, y2, amount))
+        },
+    }
+}
+
+/// 线性外插 - 按端点所在段的插值方式取斜率, Constant 段为水平
+pub fn edge_slope_mixed<T: FrameDataValue>(curve: &FrameCurve<T>, end: bool) -> EdgeSlope<T> {
+    let (pre, next) = edge_segment(&curve.frames, end)?;
+    let value1 = curve.cubic_spline_values[pre].value();
+    let value2 = curve.cubic_spline_values[next].value();
+    let frame_delta = curve.frames[next] - curve.frames[pre];
+
+    let interpolation = curve.interpolations.get(pre).cloned().unwrap_or(EFrameInterpolation::Hermite);
+    match interpolation {
+        EFrameInterpolation::Constant => None,
+        EFrameInterpolation::Linear => segment_slope(value1, value2, frame_delta),
+        EFrameInterpolation::Hermite => edge_slope_cubic_splice(curve, end),
+        EFrameInterpolation::Bezier(x1, y1, x2, y2) => {
+            let (delta, rate) = segment_slope(value1, value2, frame_delta)?;
+            Some((delta, rate * cubic_bezier_edge_slope(x1, y1, x2, y2, end)))
+        },
+    }
+}
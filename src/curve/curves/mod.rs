@@ -1,29 +1,44 @@
-use std::{fmt::Debug, intrinsics::size_of};
+use std::{fmt::Debug, mem::{size_of, size_of_val}};
 
-use crate::{easing::{EEasingMode, get_easing_call}, amount::AnimationAmountCalc};
+use crate::{easing::{EEasingMode, get_easing_call}, amount::AnimationAmountCalc, noise::NoiseParam};
 
-use self::{easing_curve::interplate_easing, frames::interplate_frame_values, minmax_curve::interplate_minmaxcurve, cubic_splice::interplate_cubic_splice, cubic_bezier_curve::interplate_cubebezier};
-
-use super::{frame::{FrameDataValue, KeyFrameCurveValue, CurveFrameValue}, FrameIndex, FramePerSecond};
+use super::{modifier::{CurveModifier, ECurveModifier}, frame::{FrameDataValue, FrameValueAlpha, FrameValueDistance, KeyFrameCurveValue, KeyFrameTime, CurveFrameValue}, tangent::{ETangentMode, EWeightedMode, FrameValueTangent}, FrameIndex, FramePerSecond};
 
 mod frames;
 mod cubic_bezier_curve;
 mod cubic_splice;
 mod minmax_curve;
 mod easing_curve;
+mod catmull_rom;
+mod frame_bezier;
+mod mixed;
+mod wrap;
+mod path;
+mod noise_curve;
+mod oscillator;
+mod gradient;
 
 pub use frames::*;
 pub use cubic_bezier_curve::*;
 pub use cubic_splice::*;
 pub use minmax_curve::*;
 pub use easing_curve::*;
+pub use catmull_rom::*;
+pub use frame_bezier::*;
+pub use mixed::*;
+pub use wrap::*;
+pub use path::*;
+pub use noise_curve::*;
+pub use oscillator::*;
+pub use gradient::*;
 
 #[derive(Debug)]
 pub enum EFrameCurveType {
     /// 关键帧数值 - Linear
     /// 帧数据数组[ frameIndex[], value[] ]
     FrameValues = 0x00,
-    /// 基础值 + 缩放值 + 曲线(f32)
+    /// 关键帧数值 - Step
+    /// 帧数据数组[ frameIndex[], value[] ] + 步进取值方式 (Previous / Next / Nearest)
     FrameValuesStep = 0x01,
     /// Easing曲线
     /// 基础value + 缩放value + Easing模式
@@ -38,6 +53,45 @@ pub enum EFrameCurveType {
     /// GLTF Cubic Spline interpolation 
     /// https://github.com/KhronosGroup/glTF-Tutorials/blob/master/gltfTutorial/gltfTutorial_007_Animations.md
     GLTFCubicSpline = 0x05,
+    /// 帧数据数组[ frameIndex[], value[] ]
+    /// Catmull-Rom 样条插值, 无需切线 (Uniform / Centripetal / Chordal)
+    CatmullRom = 0x06,
+    /// 帧数据数组[ frameIndex[], value[], (x1, y1, x2, y2)[] ]
+    /// 每段使用独立的 2D 三次贝塞尔时间曲线 (类 After Effects / Spine)
+    FrameBezier = 0x07,
+    /// 帧数据数组[ frameIndex[], <intanget, value, outtanget>[], interpolation[] ]
+    /// 每个关键帧指定到下一关键帧的插值方式 (Constant / Linear / Hermite / Bezier)
+    Mixed = 0x08,
+    /// 路径点数组[ <intanget, value, outtanget>[] ] + 弧长表
+    /// 按弧长匀速运动的 Hermite 路径, 可获取切线方向
+    Path = 0x09,
+    /// 噪声曲线
+    /// 基础value + 缩放value + 噪声参数 (种子, 频率, 层数, 振幅)
+    Noise = 0x0A,
+    /// 周期曲线
+    /// 基础value + 缩放value + 波形 + (周期, 相位, 振幅, 偏移)
+    Oscillator = 0x0B,
+    /// 渐变曲线 (类 Unity Gradient)
+    /// 颜色关键帧数组[ time[], value[] ] + 透明度关键帧数组[ time[], alpha[] ], time 为 [0, 1] 归一化时间
+    Gradient = 0x0D,
+}
+
+/// 曲线类型附加参数, 仅对应类型的曲线使用
+#[derive(Debug, Clone, Copy)]
+pub enum ECurvePayload {
+    /// 无附加参数
+    None,
+    /// 噪声参数 [Noise]
+    Noise(NoiseParam),
+    /// 周期曲线参数 [Oscillator]
+    Oscillator {
+        /// 波形
+        waveform: EWaveform,
+        /// 波形函数
+        wave: fn(KeyFrameCurveValue) -> KeyFrameCurveValue,
+        /// (周期帧数, 相位, 振幅, 偏移)
+        args: [KeyFrameCurveValue; 4],
+    },
 }
 
 pub struct FrameCurve<T: FrameDataValue> {
@@ -53,25 +107,67 @@ pub struct FrameCurve<T: FrameDataValue> {
     easing_mode: EEasingMode,
     /// 曲线拓展数据 [CubicBezier的参数]
     cubic_bezier_args: [KeyFrameCurveValue; 4],
+    /// Catmull-Rom 参数化方式 [CatmullRom]
+    catmull_rom_mode: ECatmullRomMode,
+    /// 步进取值方式 [FrameValuesStep]
+    frame_step_mode: EFrameStepMode,
+    /// 曲线类型附加参数 [Noise / Oscillator]
+    payload: ECurvePayload,
+    /// MinMaxCurve 模式 [MinMaxCurve]
+    minmax_curve_mode: EMinMaxCurveMode,
+    /// MinMaxCurve 曲线倍数 [MinMaxCurve - Curve / TwoCurves]
+    minmax_curve_multiplier: KeyFrameCurveValue,
+    /// MinMaxCurve 常数 (最小值, 最大值) [MinMaxCurve - Constant / TwoConstants]
+    minmax_constants: [KeyFrameCurveValue; 2],
+    /// 渐变混合方式 [Gradient]
+    gradient_mode: EGradientMode,
+    /// 起始帧之前的求值方式
+    pre_wrap_mode: ECurveWrapMode,
+    /// 结束帧之后的求值方式
+    post_wrap_mode: ECurveWrapMode,
 
-    /// 帧序号值
-    pub frames: Vec<FrameIndex>,
+    /// 关键帧位置 (帧), 可为小数帧
+    pub frames: Vec<KeyFrameCurveValue>,
 
     /// For MinMaxCurve
     pub minmax_curve_values: Vec<CurveFrameValue<KeyFrameCurveValue>>,
+    /// For MinMaxCurve - TwoCurves 模式最小值曲线的关键帧位置
+    pub minmax_curve_min_frames: Vec<KeyFrameCurveValue>,
+    /// For MinMaxCurve - TwoCurves 模式最小值曲线
+    pub minmax_curve_min_values: Vec<CurveFrameValue<KeyFrameCurveValue>>,
 
-    /// For FrameValues | FrameValuesStep
+    /// For FrameValues | FrameValuesStep | CatmullRom | FrameBezier | Gradient
     pub values: Vec<T>,
-    /// For GLTFCubicSpline
+    /// For GLTFCubicSpline | Mixed
     pub cubic_spline_values: Vec<CurveFrameValue<T>>,
+    /// For FrameBezier - 关键帧到下一关键帧的贝塞尔控制柄 (x1, y1, x2, y2)
+    pub bezier_handles: Vec<[KeyFrameCurveValue; 4]>,
+    /// For Mixed - 关键帧到下一关键帧的插值方式
+    pub interpolations: Vec<EFrameInterpolation>,
+    /// For Path - 累计弧长表, 每段 PATH_SEGMENT_SAMPLES 个采样
+    pub path_lengths: Vec<KeyFrameCurveValue>,
+    /// For Gradient - 透明度关键帧的归一化时间
+    pub gradient_alpha_frames: Vec<KeyFrameCurveValue>,
+    /// For Gradient - 透明度关键帧
+    pub gradient_alphas: Vec<KeyFrameCurveValue>,
+    /// 修改器栈, 按顺序作用于曲线求值, 未添加修改器时为 None
+    /// 多数曲线不使用修改器, 装箱后只占一个指针
+    #[allow(clippy::box_collection)]
+    modifiers: Option<Box<Vec<CurveModifier<T>>>>,
 
     /// 起始帧
-    pub min_frame: FrameIndex,
+    pub min_frame: KeyFrameCurveValue,
     /// 结束帧
-    pub max_frame: FrameIndex,
+    pub max_frame: KeyFrameCurveValue,
     /// 动画帧数
-    pub frame_number: FrameIndex,
+    pub frame_number: KeyFrameCurveValue,
     call: fn(&Self, KeyFrameCurveValue, &AnimationAmountCalc) -> T,
+    /// 线性外插时端点处的斜率, 参数为是否为结束端点
+    edge_slope: fn(&Self, bool) -> EdgeSlope<T>,
+    /// 关键帧插入/移动后重新计算非 Free 模式的关键帧切线
+    update_tangents: fn(&mut Self),
+    /// 关键帧插入/移动后同步逐关键帧数据 (贝塞尔控制柄, 插值方式) - (原序号, 新序号), 原序号为 None 时插入默认数据
+    update_key_data: fn(&mut Self, Option<usize>, usize),
     /// 当不使用关键帧做动画曲线时,指定的数据曲线函数
     /// 对应的 self.call 为 interplate_easing
     pub easing: fn(KeyFrameCurveValue) -> KeyFrameCurveValue,
@@ -88,10 +184,26 @@ impl<T: Debug + FrameDataValue> Debug for FrameCurve<T> {
         f.debug_struct("FrameCurve")
             .field("easing_mode", &self.easing_mode)
             .field("cubic_bezier_args", &self.cubic_bezier_args)
+            .field("catmull_rom_mode", &self.catmull_rom_mode)
+            .field("frame_step_mode", &self.frame_step_mode)
+            .field("payload", &self.payload)
+            .field("minmax_curve_mode", &self.minmax_curve_mode)
+            .field("minmax_curve_multiplier", &self.minmax_curve_multiplier)
+            .field("minmax_constants", &self.minmax_constants)
+            .field("gradient_mode", &self.gradient_mode)
+            .field("pre_wrap_mode", &self.pre_wrap_mode)
+            .field("post_wrap_mode", &self.post_wrap_mode)
             .field("design_frame_per_second", &self.design_frame_per_second)
             .field("curve_values", &self.minmax_curve_values)
+            .field("curve_min_values", &self.minmax_curve_min_values)
             .field("frames", &self.frames)
             .field("values", &self.values)
+            .field("bezier_handles", &self.bezier_handles)
+            .field("interpolations", &self.interpolations)
+            .field("path_lengths", &self.path_lengths)
+            .field("gradient_alpha_frames", &self.gradient_alpha_frames)
+            .field("gradient_alphas", &self.gradient_alphas)
+            .field("modifiers", &self.modifiers)
             .field("value_offset", &self.value_offset)
             .field("value_scalar", &self.value_scalar)
             .field("min_frame", &self.min_frame)
@@ -103,75 +215,264 @@ impl<T: Debug + FrameDataValue> Debug for FrameCurve<T> {
 
 impl<T: FrameDataValue> FrameCurve<T> {
     pub fn size(&self) -> usize {
-        1 + 1 + 4 * 4 + 2 + 2 + 2 + 2 + 8 + 8 
-        + size_of::<FrameIndex>() * self.frames.len() 
+        1 + 1 + 4 * 4 + 1 + 1 + size_of::<ECurvePayload>() + 1 + 4 + 4 * 2 + 1 + 1 + 1 + 2 + 2 + 2 + 2 + 8 + 8 
+        + size_of::<KeyFrameCurveValue>() * self.frames.len()
         + size_of::<T>() * self.values.len()
-        + size_of::<T>() * 3 * self.cubic_spline_values.len()
-        + size_of::<KeyFrameCurveValue>() * 3 * self.minmax_curve_values.len()
+        + size_of::<CurveFrameValue<T>>() * self.cubic_spline_values.len()
+        + size_of::<CurveFrameValue<KeyFrameCurveValue>>() * self.minmax_curve_values.len()
+        + size_of::<KeyFrameCurveValue>() * self.minmax_curve_min_frames.len()
+        + size_of::<CurveFrameValue<KeyFrameCurveValue>>() * self.minmax_curve_min_values.len()
+        + size_of::<KeyFrameCurveValue>() * 4 * self.bezier_handles.len()
+        + size_of::<EFrameInterpolation>() * self.interpolations.len()
+        + size_of::<KeyFrameCurveValue>() * self.path_lengths.len()
+        + size_of::<KeyFrameCurveValue>() * (self.gradient_alpha_frames.len() + self.gradient_alphas.len())
+        + size_of_val(self.modifiers())
     }
     pub fn interple(&self, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
-        let call = &self.call;
         let target_frame = target_frame * self.design_frame_per_second as KeyFrameCurveValue;
-        call(&self, target_frame, amountcalc)
+        self._interple_call(self.call, self.edge_slope, target_frame, amountcalc)
+    }
+    /// 使用指定的插值函数求值, 包含区间外求值与修改器栈
+    ///
+    /// * [slope] - 与插值函数对应的端点斜率, 用于线性外插
+    fn _interple_call(&self, call: fn(&Self, KeyFrameCurveValue, &AnimationAmountCalc) -> T, slope: fn(&Self, bool) -> EdgeSlope<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+        match &self.modifiers {
+            Some(modifiers) => self._interple_modifiers(modifiers, call, slope, target_frame, amountcalc),
+            None => self._interple_frame(call, slope, target_frame, amountcalc),
+        }
+    }
+    fn _interple_frame(&self, call: fn(&Self, KeyFrameCurveValue, &AnimationAmountCalc) -> T, slope: fn(&Self, bool) -> EdgeSlope<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+        if target_frame < self.min_frame && self.pre_wrap_mode != ECurveWrapMode::Constant {
+            self._interple_wrap(call, slope, self.pre_wrap_mode, target_frame, self.min_frame, amountcalc)
+        } else if target_frame > self.max_frame && self.post_wrap_mode != ECurveWrapMode::Constant {
+            self._interple_wrap(call, slope, self.post_wrap_mode, target_frame, self.max_frame, amountcalc)
+        } else {
+            call(self, target_frame, amountcalc)
+        }
     }
 
-    /// 曲线 - 线性插值帧 - 无曲线描述,仅关键 帧-值
+    /// 添加修改器到修改器栈末尾, 返回其序号
+    pub fn add_modifier(&mut self, modifier: CurveModifier<T>) -> usize {
+        let modifiers = self.modifiers.get_or_insert_with(Default::default);
+        modifiers.push(modifier);
+        modifiers.len() - 1
+    }
+    pub fn modifiers(&self) -> &[CurveModifier<T>] {
+        self.modifiers.as_deref().map_or(&[], |modifiers| modifiers.as_slice())
+    }
+    pub fn modifier_mut(&mut self, index: usize) -> Option<&mut CurveModifier<T>> {
+        self.modifiers.as_mut().and_then(|modifiers| modifiers.get_mut(index))
+    }
+
+    /// 经过修改器栈求值
     ///
-    pub fn curve_frame_values(design_frame_per_second: FramePerSecond) -> FrameCurve<T> {
+    /// 时间修改器按逆序修改目标帧后求值, 再按顺序应用数值修改器
+    fn _interple_modifiers(&self, modifiers: &[CurveModifier<T>], call: fn(&Self, KeyFrameCurveValue, &AnimationAmountCalc) -> T, slope: fn(&Self, bool) -> EdgeSlope<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+        let frame = self._modifier_frame(modifiers, target_frame);
+        let mut value = self._interple_frame(call, slope, frame, amountcalc);
+
+        for (index, modifier) in modifiers.iter().enumerate() {
+            if modifier.mute {
+                continue;
+            }
+
+            if let ECurveModifier::Cycles { before, after } = modifier.mode {
+                // CycleWithOffset 按该修改器所见的目标帧累加首尾数值差
+                let frame = self._modifier_frame(&modifiers[index + 1..], target_frame);
+                let mode = if frame < self.min_frame { before } else if frame > self.max_frame { after } else { continue };
+                if mode == ECurveWrapMode::CycleWithOffset {
+                    let (_, cycles) = wrap_frame(mode, frame, self.min_frame, self.max_frame);
+                    let cycles = cycles * modifier.influence;
+                    let start = self._interple_frame(call, slope, self.min_frame, amountcalc);
+                    let end = self._interple_frame(call, slope, self.max_frame, amountcalc);
+                    value = value.append(&end, cycles).append(&start, -cycles);
+                }
+            } else {
+                value = modifier.modify_value(value, target_frame, self.design_frame_per_second);
+            }
+        }
+
+        value
+    }
+    /// 按逆序应用时间修改器
+    fn _modifier_frame(&self, modifiers: &[CurveModifier<T>], target_frame: KeyFrameCurveValue) -> KeyFrameCurveValue {
+        modifiers.iter().rev()
+            .filter(|modifier| !modifier.mute && modifier.is_time_modifier())
+            .fold(target_frame, |frame, modifier| modifier.modify_frame(frame, self.min_frame, self.max_frame))
+    }
+
+    pub fn pre_wrap_mode(&self) -> ECurveWrapMode {
+        self.pre_wrap_mode
+    }
+    pub fn post_wrap_mode(&self) -> ECurveWrapMode {
+        self.post_wrap_mode
+    }
+    /// 设置曲线区间外的求值方式
+    ///
+    /// * [pre] - 起始帧之前
+    /// * [post] - 结束帧之后
+    ///
+    pub fn set_wrap_mode(&mut self, pre: ECurveWrapMode, post: ECurveWrapMode) {
+        self.pre_wrap_mode = pre;
+        self.post_wrap_mode = post;
+    }
+
+    /// 区间外求值
+    ///
+    /// * [edge_frame] - 越过的端点帧
+    fn _interple_wrap(&self, call: fn(&Self, KeyFrameCurveValue, &AnimationAmountCalc) -> T, slope: fn(&Self, bool) -> EdgeSlope<T>, mode: ECurveWrapMode, target_frame: KeyFrameCurveValue, edge_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+        if self.frame_number <= 0. {
+            return call(self, target_frame, amountcalc);
+        }
+
+        match mode {
+            ECurveWrapMode::Linear => {
+                // 沿端点处的切线外插, 没有切线时保持端点数值
+                let edge = call(self, edge_frame, amountcalc);
+                match slope(self, edge_frame == self.max_frame) {
+                    Some((direction, rate)) => edge.append(&direction, rate * (target_frame - edge_frame)),
+                    None => edge,
+                }
+            },
+            ECurveWrapMode::CycleWithOffset => {
+                let (frame, cycles) = wrap_frame(mode, target_frame, self.min_frame, self.max_frame);
+                let start = call(self, self.min_frame, amountcalc);
+                let end = call(self, self.max_frame, amountcalc);
+                call(self, frame, amountcalc).append(&end, cycles).append(&start, -cycles)
+            },
+            _ => {
+                let (frame, _) = wrap_frame(mode, target_frame, self.min_frame, self.max_frame);
+                call(self, frame, amountcalc)
+            },
+        }
+    }
+
+    /// 曲线的默认字段, 各类型曲线在此基础上覆盖所需字段
+    ///
+    /// * [call] - 插值函数
+    ///
+    fn base(design_frame_per_second: FramePerSecond, call: fn(&Self, KeyFrameCurveValue, &AnimationAmountCalc) -> T) -> FrameCurve<T> {
         FrameCurve {
             design_frame_per_second,
             value_offset: None,
             value_scalar: None,
             easing_mode: EEasingMode::None,
             cubic_bezier_args: [0., 0., 1., 1.],
+            catmull_rom_mode: ECatmullRomMode::Uniform,
+            frame_step_mode: EFrameStepMode::Nearest,
+            payload: ECurvePayload::None,
+            minmax_curve_mode: EMinMaxCurveMode::Curve,
+            minmax_curve_multiplier: 1.,
+            minmax_constants: [0., 0.],
+            gradient_mode: EGradientMode::Blend,
+            pre_wrap_mode: ECurveWrapMode::Constant,
+            post_wrap_mode: ECurveWrapMode::Constant,
             frames: vec![],
             minmax_curve_values: vec![],
+            minmax_curve_min_frames: vec![],
+            minmax_curve_min_values: vec![],
             values: vec![],
             cubic_spline_values: vec![],
-            min_frame: FrameIndex::MAX,
-            max_frame: FrameIndex::MIN,
-            frame_number: 0 as FrameIndex,
-            call: interplate_frame_values::<T>,
+            bezier_handles: vec![],
+            interpolations: vec![],
+            path_lengths: vec![],
+            gradient_alpha_frames: vec![],
+            gradient_alphas: vec![],
+            modifiers: None,
+            min_frame: KeyFrameCurveValue::MAX,
+            max_frame: KeyFrameCurveValue::MIN,
+            frame_number: 0.,
+            call,
+            edge_slope: edge_slope_flat::<T>,
+            update_tangents: Self::_update_tangents_none,
+            update_key_data: Self::_update_key_data_none,
             easing: get_easing_call(EEasingMode::None),
         }
     }
+
+    /// 曲线 - 线性插值帧 - 无曲线描述,仅关键 帧-值
+    ///
+    pub fn curve_frame_values(design_frame_per_second: FramePerSecond) -> FrameCurve<T> {
+        FrameCurve {
+            edge_slope: edge_slope_frame_values::<T>,
+            ..Self::base(design_frame_per_second, interplate_frame_values::<T>)
+        }
+    }
     /// 曲线关键帧 - 线性插值帧 - 无曲线描述,仅关键 帧-值
     ///
     /// * [framecurve] - 目标曲线
     /// * [frame] - 帧位置
     /// * [value] - 帧数值
     ///
-    pub fn curve_frame_values_frame(&mut self, frame: FrameIndex, value: T) {
-        let index = self.frames.binary_search(&frame).unwrap_or_else(|x| x);
-        self.frames.insert(index, frame);
-        self.values.insert(index, value);
+    pub fn curve_frame_values_frame(&mut self, frame: impl KeyFrameTime, value: T) {
+        self._frame_values_insert(frame.frame_time(), value);
+    }
 
-        let len = self.frames.len();
-        let min = self.frames[0];
-        let max = self.frames[len - 1];
+    fn _frame_values_insert(&mut self, frame: KeyFrameCurveValue, value: T) -> usize {
+        let (index, min, max) = curve_frame_index(&mut self.frames, frame);
+        self.values.insert(index, value);
 
         self.min_frame = min;
         self.max_frame = max;
         self.frame_number = max - min;
+
+        let call = self.update_key_data;
+        call(self, None, index);
+
+        index
+    }
+    /// 曲线 - 步进插值帧 - 无曲线描述,仅关键 帧-值, 关键帧使用 curve_frame_values_frame 添加
+    ///
+    /// * [mode] - 两关键帧之间的取值方式
+    ///
+    pub fn curve_frame_values_step(design_frame_per_second: FramePerSecond, mode: EFrameStepMode) -> FrameCurve<T> {
+        FrameCurve {
+            frame_step_mode: mode,
+            ..Self::base(design_frame_per_second, EFrameStepMode::get_interplate_call::<T>(mode))
+        }
+    }
+    pub fn frame_step_mode(&self) -> EFrameStepMode {
+        self.frame_step_mode
+    }
+    /// 曲线 - 贝塞尔插值帧 - 每段使用独立的贝塞尔控制柄描述进度
+    ///
+    pub fn curve_frame_bezier(design_frame_per_second: FramePerSecond) -> FrameCurve<T> {
+        FrameCurve {
+            edge_slope: edge_slope_frame_bezier::<T>,
+            update_key_data: Self::_update_bezier_handles,
+            ..Self::base(design_frame_per_second, interplate_frame_bezier::<T>)
+        }
+    }
+    /// 曲线关键帧 - 贝塞尔插值帧
+    ///
+    /// * [framecurve] - 目标曲线
+    /// * [frame] - 帧位置
+    /// * [value] - 帧数值
+    /// * [x1,y1,x2,y2] - 该关键帧到下一关键帧的 CubicBezier 控制柄 (https://cubic-bezier.com/)
+    ///
+    /// 使用 curve_frame_values_frame 添加的关键帧控制柄为线性 (0, 0, 1, 1)
+    pub fn curve_frame_bezier_frame(
+        &mut self,
+        frame: impl KeyFrameTime,
+        value: T,
+        x1: KeyFrameCurveValue,
+        y1: KeyFrameCurveValue,
+        x2: KeyFrameCurveValue,
+        y2: KeyFrameCurveValue,
+    ) {
+        let index = self._frame_values_insert(frame.frame_time(), value);
+        self.bezier_handles[index] = [x1, y1, x2, y2];
+    }
+
+    fn _update_bezier_handles(&mut self, from: Option<usize>, to: usize) {
+        update_key_data(&mut self.bezier_handles, from, to, [0., 0., 1., 1.]);
     }
     /// 曲线 - 线性插值帧 - 无曲线描述,仅关键 帧-值
     ///
     pub fn curve_cubic_spline(design_frame_per_second: FramePerSecond) -> FrameCurve<T> {
         FrameCurve {
-            design_frame_per_second,
-            value_offset: None,
-            value_scalar: None,
-            easing_mode: EEasingMode::None,
-            cubic_bezier_args: [0., 0., 1., 1.],
-            frames: vec![],
-            minmax_curve_values: vec![],
-            values: vec![],
-            cubic_spline_values: vec![],
-            min_frame: FrameIndex::MAX,
-            max_frame: FrameIndex::MIN,
-            frame_number: 0 as FrameIndex,
-            call: interplate_cubic_splice::<T>,
-            easing: get_easing_call(EEasingMode::None),
+            edge_slope: edge_slope_cubic_splice::<T>,
+            ..Self::base(design_frame_per_second, interplate_cubic_splice::<T>)
         }
     }
     /// 曲线关键帧 - 线性插值帧 - 无曲线描述,仅关键 帧-值
@@ -180,20 +481,71 @@ impl<T: FrameDataValue> FrameCurve<T> {
     /// * [frame] - 帧位置
     /// * [value] - 帧数值
     ///
-    pub fn curve_cubic_splice_frame(&mut self, frame: FrameIndex, value: T, intangent: T, outtangent: T) {
+    pub fn curve_cubic_splice_frame(&mut self, frame: impl KeyFrameTime, value: T, intangent: T, outtangent: T) {
         let keyframe = CurveFrameValue::new(value, [intangent, outtangent]);
+        self._cubic_splice_insert(frame.frame_time(), keyframe, None);
+    }
 
-        let index = self.frames.binary_search(&frame).unwrap_or_else(|x| x);
-        self.frames.insert(index, frame);
-        self.cubic_spline_values.insert(index, keyframe);
+    /// 移动 GLTFCubicSpline 曲线的关键帧, 保留其切线与切线模式
+    ///
+    /// * [index] - 关键帧在帧数组中的序号
+    /// * [frame] - 新的帧位置
+    /// * [value] - 新的帧数值
+    ///
+    /// 返回关键帧移动后的序号
+    pub fn curve_cubic_splice_move_frame(&mut self, index: usize, frame: impl KeyFrameTime, value: T) -> usize {
+        self.frames.remove(index);
+        let mut keyframe = self.cubic_spline_values.remove(index);
+        keyframe.set_value(value);
 
-        let len = self.frames.len();
-        let min = self.frames[0];
-        let max = self.frames[len - 1];
+        self._cubic_splice_insert(frame.frame_time(), keyframe, Some(index))
+    }
+
+    /// 曲线 - 逐段指定插值方式的关键帧曲线
+    ///
+    pub fn curve_mixed(design_frame_per_second: FramePerSecond) -> FrameCurve<T> {
+        FrameCurve {
+            edge_slope: edge_slope_mixed::<T>,
+            update_key_data: Self::_update_interpolations,
+            ..Self::base(design_frame_per_second, interplate_mixed::<T>)
+        }
+    }
+    /// 曲线关键帧 - 逐段指定插值方式的关键帧曲线
+    ///
+    /// * [framecurve] - 目标曲线
+    /// * [frame] - 帧位置
+    /// * [value] - 帧数值
+    /// * [intangent] - In Tangent (Hermite 插值时使用)
+    /// * [outtangent] - Out Tangent (Hermite 插值时使用)
+    /// * [interpolation] - 该关键帧到下一关键帧的插值方式
+    ///
+    /// 使用 curve_cubic_splice_frame 添加的关键帧插值方式为 Hermite
+    pub fn curve_mixed_frame(&mut self, frame: impl KeyFrameTime, value: T, intangent: T, outtangent: T, interpolation: EFrameInterpolation) {
+        let keyframe = CurveFrameValue::new(value, [intangent, outtangent]);
+        let index = self._cubic_splice_insert(frame.frame_time(), keyframe, None);
+        self.interpolations[index] = interpolation;
+    }
+
+    fn _update_interpolations(&mut self, from: Option<usize>, to: usize) {
+        update_key_data(&mut self.interpolations, from, to, EFrameInterpolation::Hermite);
+    }
+
+    /// * [from] - 移动关键帧时的原序号, 新关键帧为 None
+    fn _cubic_splice_insert(&mut self, frame: KeyFrameCurveValue, keyframe: CurveFrameValue<T>, from: Option<usize>) -> usize {
+        let (index, min, max) = curve_frame_index(&mut self.frames, frame);
+        self.cubic_spline_values.insert(index, keyframe);
 
         self.min_frame = min;
         self.max_frame = max;
         self.frame_number = max - min;
+
+        let call = self.update_key_data;
+        call(self, from, index);
+
+        let call = self.update_tangents;
+        call(self);
+
+        index
     }
 
     /// 曲线 - Hermit插值曲线
@@ -207,20 +559,11 @@ impl<T: FrameDataValue> FrameCurve<T> {
         design_frame_per_second: FramePerSecond,
     ) -> FrameCurve<T> {
         FrameCurve {
-            design_frame_per_second,
             value_offset: Some(from),
             value_scalar: Some(scalar),
-            easing_mode: EEasingMode::None,
-            cubic_bezier_args: [0., 0., 1., 1.],
-            frames: vec![],
-            minmax_curve_values: vec![],
-            values: vec![],
-            cubic_spline_values: vec![],
-            min_frame: FrameIndex::MAX,
-            max_frame: FrameIndex::MIN,
-            frame_number: 0 as FrameIndex,
-            call: interplate_minmaxcurve::<T>,
-            easing: get_easing_call(EEasingMode::None),
+            edge_slope: edge_slope_minmaxcurve::<T>,
+            update_tangents: Self::_update_minmax_curve_tangents,
+            ..Self::base(design_frame_per_second, interplate_minmaxcurve::<T>)
         }
     }
 
@@ -234,25 +577,240 @@ impl<T: FrameDataValue> FrameCurve<T> {
     ///
     pub fn curve_minmax_curve_frame(
         &mut self,
-        frame: FrameIndex,
+        frame: impl KeyFrameTime,
         value: KeyFrameCurveValue,
         intangent: KeyFrameCurveValue,
         outtangent: KeyFrameCurveValue,
     ) {
         let keyframe = CurveFrameValue::new(value, [intangent, outtangent]);
+        self._minmax_curve_insert(frame.frame_time(), keyframe);
+    }
+
+    /// 曲线关键帧 - Hermit插值曲线 - 带切线权重 (类 Unity WeightedMode)
+    ///
+    /// 启用权重的区间按 (时间, 数值) 二维贝塞尔曲线求值, 权重为 1/3 时与 hermite 插值一致
+    ///
+    /// * [framecurve] - 目标曲线
+    /// * [frame] - 帧位置
+    /// * [value] - 帧数值
+    /// * [intangent] - In Tangent
+    /// * [outtangent] - Out Tangent
+    /// * [weights] - [In Weight, Out Weight], 为相邻区间时长的比例 [0, 1]
+    /// * [weighted_mode] - 启用权重的切线
+    ///
+    pub fn curve_minmax_curve_frame_weighted(
+        &mut self,
+        frame: impl KeyFrameTime,
+        value: KeyFrameCurveValue,
+        intangent: KeyFrameCurveValue,
+        outtangent: KeyFrameCurveValue,
+        weights: [KeyFrameCurveValue; 2],
+        weighted_mode: EWeightedMode,
+    ) {
+        let mut keyframe = CurveFrameValue::new(value, [intangent, outtangent]);
+        keyframe.set_weights(weights[0], weights[1], weighted_mode);
+        self._minmax_curve_insert(frame.frame_time(), keyframe);
+    }
+
+    /// 曲线关键帧 - Hermit插值曲线 - 切线由切线模式自动计算
+    ///
+    /// * [framecurve] - 目标曲线
+    /// * [frame] - 帧位置
+    /// * [value] - 帧数值
+    /// * [mode] - 切线模式 (Free 模式切线为 0)
+    ///
+    pub fn curve_minmax_curve_frame_mode(
+        &mut self,
+        frame: impl KeyFrameTime,
+        value: KeyFrameCurveValue,
+        mode: ETangentMode,
+    ) {
+        let keyframe = CurveFrameValue::new_with_mode(value, [0., 0.], mode);
+        self._minmax_curve_insert(frame.frame_time(), keyframe);
+    }
 
-        let index = self.frames.binary_search(&frame).unwrap_or_else(|x| x);
-        self.frames.insert(index, frame);
+    /// 移动 Hermit插值曲线 的关键帧, 保留其切线模式
+    ///
+    /// * [index] - 关键帧在帧数组中的序号
+    /// * [frame] - 新的帧位置
+    /// * [value] - 新的帧数值
+    ///
+    /// 返回关键帧移动后的序号
+    pub fn curve_minmax_curve_move_frame(
+        &mut self,
+        index: usize,
+        frame: impl KeyFrameTime,
+        value: KeyFrameCurveValue,
+    ) -> usize {
+        self.frames.remove(index);
+        let mut keyframe = self.minmax_curve_values.remove(index);
+        keyframe.set_value(value);
+        self._minmax_curve_insert(frame.frame_time(), keyframe)
+    }
+
+    fn _minmax_curve_insert(&mut self, frame: KeyFrameCurveValue, keyframe: CurveFrameValue<KeyFrameCurveValue>) -> usize {
+        let (index, _, _) = curve_frame_index(&mut self.frames, frame);
         self.minmax_curve_values.insert(index, keyframe);
 
-        let len = self.frames.len();
-        let min = self.frames[0];
-        let max = self.frames[len - 1];
+        self._update_minmax_curve_range();
 
-        self.min_frame = min;
-        self.max_frame = max;
-        self.frame_number = max - min;
+        let call = self.update_tangents;
+        call(self);
+
+        index
+    }
+
+    /// TwoCurves 模式的帧范围为两条曲线帧范围的并集
+    fn _update_minmax_curve_range(&mut self) {
+        let firsts = self.frames.first().into_iter().chain(self.minmax_curve_min_frames.first());
+        let lasts = self.frames.last().into_iter().chain(self.minmax_curve_min_frames.last());
+
+        self.min_frame = firsts.fold(KeyFrameCurveValue::MAX, |a, b| KeyFrameCurveValue::min(a, *b));
+        self.max_frame = lasts.fold(KeyFrameCurveValue::MIN, |a, b| KeyFrameCurveValue::max(a, *b));
+        self.frame_number = self.max_frame - self.min_frame;
+    }
+
+    /// MinMaxCurve 的切线单位为 数值/区间, 需要乘以相邻区间的帧数
+    fn _update_minmax_curve_tangents(&mut self) {
+        Self::_minmax_curve_tangents(&self.frames, &mut self.minmax_curve_values);
+        Self::_minmax_curve_tangents(&self.minmax_curve_min_frames, &mut self.minmax_curve_min_values);
+    }
+
+    fn _minmax_curve_tangents(frames: &[KeyFrameCurveValue], values: &mut [CurveFrameValue<KeyFrameCurveValue>]) {
+        let len = frames.len();
+        for i in 0..len {
+            let mode = values[i].mode();
+            if mode == ETangentMode::Free {
+                continue;
+            }
+
+            let delta_in = if i > 0 { frames[i] - frames[i - 1] } else { 0. };
+            let delta_out = if i + 1 < len { frames[i + 1] - frames[i] } else { 0. };
+
+            let prev = if i > 0 { Some((values[i - 1].value(), delta_in)) } else { None };
+            let next = if i + 1 < len { Some((values[i + 1].value(), delta_out)) } else { None };
+
+            let (intangent, outtangent) = KeyFrameCurveValue::tangents(mode, prev, values[i].value(), next);
+            values[i].set_tangents(intangent * delta_in, outtangent * delta_out);
+        }
+    }
+
+    /// MinMaxCurve - 常数 (Constant)
+    ///
+    /// * [from] - 动画数值起点
+    /// * [scalar] - 动画数值变化域值
+    /// * [constant] - 常数, 结果为 from + scalar * constant
+    ///
+    pub fn curve_minmax_constant(
+        from: T,
+        scalar: T,
+        constant: KeyFrameCurveValue,
+        design_frame_per_second: FramePerSecond,
+    ) -> FrameCurve<T> {
+        Self::curve_minmax_two_constants(from, scalar, constant, constant, design_frame_per_second)
+    }
+
+    /// MinMaxCurve - 在两个常数之间随机 (Random Between Two Constants), 使用 interple_random 求值
+    ///
+    /// * [from] - 动画数值起点
+    /// * [scalar] - 动画数值变化域值
+    /// * [min] - 最小值常数
+    /// * [max] - 最大值常数
+    ///
+    pub fn curve_minmax_two_constants(
+        from: T,
+        scalar: T,
+        min: KeyFrameCurveValue,
+        max: KeyFrameCurveValue,
+        design_frame_per_second: FramePerSecond,
+    ) -> FrameCurve<T> {
+        let mut curve = Self::curve_minmax_curve(from, scalar, design_frame_per_second);
+        curve.minmax_curve_mode = if min == max { EMinMaxCurveMode::Constant } else { EMinMaxCurveMode::TwoConstants };
+        curve.minmax_constants = [min, max];
+        curve.min_frame = 0.;
+        curve.max_frame = 0.;
+        curve.call = interplate_minmax_constant::<T>;
+        curve.edge_slope = edge_slope_flat::<T>;
+        curve
+    }
+
+    /// MinMaxCurve - 在两条曲线之间随机 (Random Between Two Curves), 使用 interple_random 求值
+    ///
+    /// 最大值曲线关键帧使用 curve_minmax_curve_frame 等添加, 最小值曲线关键帧使用 curve_minmax_curve_min_frame 添加
+    ///
+    pub fn curve_minmax_two_curves(
+        from: T,
+        scalar: T,
+        design_frame_per_second: FramePerSecond,
+    ) -> FrameCurve<T> {
+        let mut curve = Self::curve_minmax_curve(from, scalar, design_frame_per_second);
+        curve.minmax_curve_mode = EMinMaxCurveMode::TwoCurves;
+        curve
+    }
+
+    /// 曲线关键帧 - TwoCurves 模式的最小值曲线
+    ///
+    /// * [frame] - 帧位置
+    /// * [value] - 帧数值
+    /// * [intangent] - In Tangent
+    /// * [outtangent] - Out Tangent
+    ///
+    pub fn curve_minmax_curve_min_frame(
+        &mut self,
+        frame: impl KeyFrameTime,
+        value: KeyFrameCurveValue,
+        intangent: KeyFrameCurveValue,
+        outtangent: KeyFrameCurveValue,
+    ) {
+        let keyframe = CurveFrameValue::new(value, [intangent, outtangent]);
+        let (index, _, _) = curve_frame_index(&mut self.minmax_curve_min_frames, frame.frame_time());
+        self.minmax_curve_min_values.insert(index, keyframe);
+
+        self._update_minmax_curve_range();
+
+        let call = self.update_tangents;
+        call(self);
+    }
+
+    pub fn minmax_curve_mode(&self) -> EMinMaxCurveMode {
+        self.minmax_curve_mode
+    }
+    pub fn minmax_curve_multiplier(&self) -> KeyFrameCurveValue {
+        self.minmax_curve_multiplier
+    }
+    /// 设置 Curve / TwoCurves 模式的曲线倍数
+    pub fn set_minmax_curve_multiplier(&mut self, multiplier: KeyFrameCurveValue) {
+        self.minmax_curve_multiplier = multiplier;
+    }
+    pub fn minmax_constants(&self) -> [KeyFrameCurveValue; 2] {
+        self.minmax_constants
+    }
+
+    /// 带随机数的插值 - 用于 MinMaxCurve 的 TwoConstants / TwoCurves 模式, 其他曲线与 interple 一致
+    ///
+    /// * [target_frame] - 目标时间 (秒)
+    /// * [random] - 每个实例的随机数 [0, 1], 0 为最小值, 1 为最大值
+    ///
+    pub fn interple_random(&self, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc, random: KeyFrameCurveValue) -> T {
+        let min_call = match self.minmax_curve_mode {
+            EMinMaxCurveMode::TwoConstants => interplate_minmax_constant_min::<T>,
+            EMinMaxCurveMode::TwoCurves => interplate_minmaxcurve_min::<T>,
+            _ => return self.interple(target_frame, amountcalc),
+        };
+        let min_slope = match self.minmax_curve_mode {
+            EMinMaxCurveMode::TwoCurves => edge_slope_minmaxcurve_min::<T>,
+            _ => edge_slope_flat::<T>,
+        };
+
+        let target_frame = target_frame * self.design_frame_per_second as KeyFrameCurveValue;
+        let min = self._interple_call(min_call, min_slope, target_frame, amountcalc);
+        let max = self._interple_call(self.call, self.edge_slope, target_frame, amountcalc);
+        min.interpolate(&max, random)
     }
+
+    fn _update_tangents_none(&mut self) {}
+
+    fn _update_key_data_none(&mut self, _: Option<usize>, _: usize) {}
     
     /// 曲线 -  Easing 缓动 - result = from + scalar * easing(t)
     ///
@@ -269,20 +827,15 @@ impl<T: FrameDataValue> FrameCurve<T> {
         easing_mode: EEasingMode,
     ) -> FrameCurve<T> {
         FrameCurve {
-            design_frame_per_second,
             value_offset: Some(from),
             value_scalar: Some(scalar),
             easing_mode,
-            cubic_bezier_args: [0., 0., 1., 1.],
-            frames: vec![],
-            minmax_curve_values: vec![],
-            values: vec![],
-            cubic_spline_values: vec![],
-            min_frame: 0 as FrameIndex,
-            max_frame: frame_count,
-            frame_number: frame_count,
-            call: interplate_easing::<T>,
+            min_frame: 0.,
+            max_frame: frame_count as KeyFrameCurveValue,
+            frame_number: frame_count as KeyFrameCurveValue,
             easing: get_easing_call(easing_mode),
+            edge_slope: edge_slope_easing::<T>,
+            ..Self::base(design_frame_per_second, interplate_easing::<T>)
         }
     }
     
@@ -292,6 +845,7 @@ impl<T: FrameDataValue> FrameCurve<T> {
     /// * [scalar] - 动画数值变化域值
     /// * [x1,y1,x2,y2] - CubicBezier 曲线参数 (https://cubic-bezier.com/)
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn curve_cubic_bezier(
         from: T,
         scalar: T,
@@ -303,68 +857,323 @@ impl<T: FrameDataValue> FrameCurve<T> {
         y2: KeyFrameCurveValue,
     ) -> FrameCurve<T> {
         FrameCurve {
-            design_frame_per_second,
             value_offset: Some(from),
             value_scalar: Some(scalar),
-            easing_mode: EEasingMode::None,
             cubic_bezier_args: [x1, y1, x2, y2],
-            frames: vec![],
-            minmax_curve_values: vec![],
-            values: vec![],
-            cubic_spline_values: vec![],
-            min_frame: 0 as FrameIndex,
-            max_frame: frame_count,
-            frame_number: frame_count,
-            call: interplate_cubebezier::<T>,
-            easing: get_easing_call(EEasingMode::None),
+            min_frame: 0.,
+            max_frame: frame_count as KeyFrameCurveValue,
+            frame_number: frame_count as KeyFrameCurveValue,
+            edge_slope: edge_slope_cubebezier::<T>,
+            ..Self::base(design_frame_per_second, interplate_cubebezier::<T>)
+        }
+    }
+    /// 曲线 - 噪声 - 带种子的一维梯度噪声, 相同种子产生相同数值
+    ///
+    /// * [from] - 噪声中心值
+    /// * [scalar] - 噪声变化域值, 结果为 from + scalar * noise, noise 范围 [-amplitude, amplitude]
+    /// * [frame_count] - 曲线帧数, 超出后噪声继续变化
+    /// * [param] - 噪声参数
+    ///
+    pub fn curve_noise(
+        from: T,
+        scalar: T,
+        frame_count: FrameIndex,
+        design_frame_per_second: FramePerSecond,
+        param: NoiseParam,
+    ) -> FrameCurve<T> {
+        FrameCurve {
+            value_offset: Some(from),
+            value_scalar: Some(scalar),
+            payload: ECurvePayload::Noise(param),
+            min_frame: 0.,
+            max_frame: frame_count as KeyFrameCurveValue,
+            frame_number: frame_count as KeyFrameCurveValue,
+            ..Self::base(design_frame_per_second, interplate_noise::<T>)
+        }
+    }
+    /// 噪声参数, 非噪声曲线返回 None
+    pub fn noise_param(&self) -> Option<NoiseParam> {
+        match self.payload {
+            ECurvePayload::Noise(param) => Some(param),
+            _ => None,
+        }
+    }
+    /// 曲线 - 周期曲线 - 无需关键帧的循环波形
+    ///
+    /// * [from] - 动画数值起始值
+    /// * [scalar] - 动画数值变化域值, 结果为 from + scalar * (offset + amplitude * wave)
+    /// * [waveform] - 波形
+    /// * [period] - 周期帧数
+    /// * [phase] - 相位, 以周期为单位
+    /// * [amplitude] - 振幅
+    /// * [offset] - 偏移
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn curve_oscillator(
+        from: T,
+        scalar: T,
+        design_frame_per_second: FramePerSecond,
+        waveform: EWaveform,
+        period: KeyFrameCurveValue,
+        phase: KeyFrameCurveValue,
+        amplitude: KeyFrameCurveValue,
+        offset: KeyFrameCurveValue,
+    ) -> FrameCurve<T> {
+        FrameCurve {
+            value_offset: Some(from),
+            value_scalar: Some(scalar),
+            payload: ECurvePayload::Oscillator { waveform, wave: EWaveform::get_wave_call(waveform), args: [period, phase, amplitude, offset] },
+            min_frame: 0.,
+            max_frame: period,
+            frame_number: period,
+            ..Self::base(design_frame_per_second, interplate_oscillator::<T>)
+        }
+    }
+    /// 波形, 非周期曲线返回 None
+    pub fn waveform(&self) -> Option<EWaveform> {
+        match self.payload {
+            ECurvePayload::Oscillator { waveform, .. } => Some(waveform),
+            _ => None,
+        }
+    }
+    /// 周期曲线参数 (周期帧数, 相位, 振幅, 偏移), 非周期曲线返回 None
+    pub fn oscillator_args(&self) -> Option<[KeyFrameCurveValue; 4]> {
+        match self.payload {
+            ECurvePayload::Oscillator { args, .. } => Some(args),
+            _ => None,
         }
     }
-
     /// 获取目标帧的前后帧在帧数组中的序号
     pub fn get_pre_next_frame_index(
-        frames: &Vec<FrameIndex>,
+        frames: &[KeyFrameCurveValue],
         target_frame: KeyFrameCurveValue,
     ) -> (usize, usize) {
-        let total_num = frames.len();
-        let index = frames
-            .binary_search(&(target_frame as FrameIndex))
-            .unwrap_or_else(|x| x);
-        if index == 0 {
-            (index, index)
-        } else if index <= total_num - 1 {
-            (index - 1, index)
+        get_pre_next_frame_index(frames, target_frame)
+    }
+}
+
+impl<T: FrameDataValue + FrameValueTangent> FrameCurve<T> {
+    /// 曲线关键帧 - GLTFCubicSpline - 切线由切线模式自动计算
+    ///
+    /// * [framecurve] - 目标曲线
+    /// * [frame] - 帧位置
+    /// * [value] - 帧数值
+    /// * [mode] - 切线模式 (Free 模式切线为 0), 仅作用于该关键帧
+    ///
+    /// 曲线存在非 Free 模式的关键帧后, 之后插入或移动任何关键帧都会重新计算这些关键帧的切线
+    pub fn curve_cubic_splice_frame_mode(&mut self, frame: impl KeyFrameTime, value: T, mode: ETangentMode) {
+        let (intangent, outtangent) = T::tangents(ETangentMode::Flat, None, &value, None);
+        let keyframe = CurveFrameValue::new_with_mode(value, [intangent, outtangent], mode);
+
+        if mode != ETangentMode::Free {
+            self.update_tangents = Self::_update_cubic_spline_tangents;
+        }
+        self._cubic_splice_insert(frame.frame_time(), keyframe, None);
+    }
+
+    /// GLTFCubicSpline 的切线单位为 数值/秒, Free 模式的关键帧保持原切线
+    fn _update_cubic_spline_tangents(&mut self) {
+        let len = self.frames.len();
+        let design_frame_per_second = self.design_frame_per_second as KeyFrameCurveValue;
+        for i in 0..len {
+            let mode = self.cubic_spline_values[i].mode();
+            if mode == ETangentMode::Free {
+                continue;
+            }
+
+            let prev = if i > 0 {
+                Some((self.cubic_spline_values[i - 1].value(), (self.frames[i] - self.frames[i - 1]) / design_frame_per_second))
+            } else {
+                None
+            };
+            let next = if i + 1 < len {
+                Some((self.cubic_spline_values[i + 1].value(), (self.frames[i + 1] - self.frames[i]) / design_frame_per_second))
+            } else {
+                None
+            };
+
+            let (intangent, outtangent) = T::tangents(mode, prev, self.cubic_spline_values[i].value(), next);
+            self.cubic_spline_values[i].set_tangents(intangent, outtangent);
+        }
+    }
+}
+
+impl<T: FrameDataValue + FrameValueDistance> FrameCurve<T> {
+    /// 曲线 - Catmull-Rom 样条插值帧 - 无切线描述,仅关键 帧-值, 曲线经过所有关键帧
+    ///
+    /// * [mode] - 参数化方式 (Uniform / Centripetal / Chordal)
+    ///
+    pub fn curve_catmull_rom(design_frame_per_second: FramePerSecond, mode: ECatmullRomMode) -> FrameCurve<T> {
+        FrameCurve {
+            catmull_rom_mode: mode,
+            edge_slope: edge_slope_frame_values::<T>,
+            ..Self::base(design_frame_per_second, interplate_catmull_rom::<T>)
+        }
+    }
+    /// 曲线关键帧 - Catmull-Rom 样条插值帧 - 无切线描述,仅关键 帧-值
+    ///
+    /// * [framecurve] - 目标曲线
+    /// * [frame] - 帧位置
+    /// * [value] - 帧数值
+    ///
+    pub fn curve_catmull_rom_frame(&mut self, frame: impl KeyFrameTime, value: T) {
+        self.curve_frame_values_frame(frame, value);
+    }
+    /// 曲线 - 路径 - 按弧长匀速经过所有路径点, 路径点使用 curve_path_point 添加
+    ///
+    /// * [frame_count] - 走完整条路径的帧数
+    ///
+    pub fn curve_path(design_frame_per_second: FramePerSecond, frame_count: impl KeyFrameTime) -> FrameCurve<T> {
+        let frame_count = frame_count.frame_time();
+        FrameCurve {
+            min_frame: 0.,
+            max_frame: frame_count,
+            frame_number: frame_count,
+            edge_slope: edge_slope_path::<T>,
+            ..Self::base(design_frame_per_second, interplate_path::<T>)
+        }
+    }
+    /// 曲线路径点 - 追加到路径末尾, 并重新计算弧长表
+    ///
+    /// * [value] - 路径点
+    /// * [intangent] - 入切线, 以路径段为单位 (等同于 3 * (P3 - P2) 的贝塞尔控制柄)
+    /// * [outtangent] - 出切线, 以路径段为单位 (等同于 3 * (P1 - P0) 的贝塞尔控制柄)
+    ///
+    pub fn curve_path_point(&mut self, value: T, intangent: T, outtangent: T) {
+        self.cubic_spline_values.push(CurveFrameValue::new(value, [intangent, outtangent]));
+        self.path_lengths = path_arc_lengths(&self.cubic_spline_values);
+    }
+    /// 路径总长度
+    pub fn path_length(&self) -> KeyFrameCurveValue {
+        self.path_lengths.last().cloned().unwrap_or(0.)
+    }
+    /// 路径插值, 返回 (位置, 单位切线方向)
+    ///
+    /// * [target_frame] - 目标时间 (秒)
+    ///
+    pub fn interple_path(&self, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> (T, T) {
+        let value = self.interple(target_frame, amountcalc);
+
+        let target_frame = target_frame * self.design_frame_per_second as KeyFrameCurveValue;
+        let mode = if target_frame < self.min_frame { self.pre_wrap_mode } else { self.post_wrap_mode };
+        let (frame, cycles) = if target_frame < self.min_frame || target_frame > self.max_frame {
+            wrap_frame(mode, target_frame, self.min_frame, self.max_frame)
+        } else {
+            (target_frame, 0.)
+        };
+
+        let tangent = interplate_path_tangent(self, frame, amountcalc);
+        if mode == ECurveWrapMode::PingPong && cycles.rem_euclid(2.) >= 1. {
+            (value, tangent.append(&tangent, -2.))
         } else {
-            (index - 1, index - 1)
+            (value, tangent)
         }
     }
 }
 
+impl<T: FrameDataValue + FrameValueAlpha> FrameCurve<T> {
+    /// 曲线 - 渐变 - 颜色与透明度关键帧各自位于 [0, 1] 归一化时间上
+    ///
+    /// * [frame_count] - 归一化时间 1 对应的帧数 (如粒子生命周期)
+    /// * [mode] - 关键帧混合方式
+    ///
+    pub fn curve_gradient(design_frame_per_second: FramePerSecond, frame_count: impl KeyFrameTime, mode: EGradientMode) -> FrameCurve<T> {
+        let frame_count = frame_count.frame_time();
+        FrameCurve {
+            gradient_mode: mode,
+            min_frame: 0.,
+            max_frame: frame_count,
+            frame_number: frame_count,
+            ..Self::base(design_frame_per_second, interplate_gradient::<T>)
+        }
+    }
+    /// 渐变颜色关键帧, 没有颜色关键帧时为不透明白色; 存在透明度关键帧时颜色自身的透明度被忽略
+    ///
+    /// * [time] - 归一化时间 [0, 1]
+    /// * [color] - 颜色
+    ///
+    pub fn curve_gradient_color_key(&mut self, time: KeyFrameCurveValue, color: T) {
+        let (index, _, _) = curve_frame_index(&mut self.frames, KeyFrameCurveValue::clamp(time, 0., 1.));
+        self.values.insert(index, color);
+    }
+    /// 渐变透明度关键帧
+    ///
+    /// * [time] - 归一化时间 [0, 1]
+    /// * [alpha] - 透明度
+    ///
+    pub fn curve_gradient_alpha_key(&mut self, time: KeyFrameCurveValue, alpha: KeyFrameCurveValue) {
+        let (index, _, _) = curve_frame_index(&mut self.gradient_alpha_frames, KeyFrameCurveValue::clamp(time, 0., 1.));
+        self.gradient_alphas.insert(index, alpha);
+    }
+}
+
+impl<T: FrameDataValue> FrameCurve<T> {
+    pub fn gradient_mode(&self) -> EGradientMode {
+        self.gradient_mode
+    }
+}
+
 /// 获取目标帧的前后帧在帧数组中的序号
+///
+/// 目标帧恰好位于关键帧上时, 返回以该关键帧为起点的区间
 pub fn get_pre_next_frame_index(
-    frames: &Vec<FrameIndex>,
+    frames: &[KeyFrameCurveValue],
     target_frame: KeyFrameCurveValue,
 ) -> (usize, usize) {
     let total_num = frames.len();
-    let index = frames
-        .binary_search(&(target_frame as FrameIndex))
-        .unwrap_or_else(|x| x);
+    let index = frames.partition_point(|frame| *frame <= target_frame);
     if index == 0 {
         (index, index)
-    } else if index <= total_num - 1 {
+    } else if index < total_num {
         (index - 1, index)
     } else {
         (index - 1, index - 1)
     }
 }
 
-/// 曲线关键帧 - 线性插值帧 - 无曲线描述,仅关键 帧-值
+/// 目标帧所在关键帧段的 (前帧序号, 后帧序号, 段内进度), 进度经 amountcalc 计算并限制在 [0, 1]
+pub fn frame_segment_amount(
+    frames: &[KeyFrameCurveValue],
+    target_frame: KeyFrameCurveValue,
+    amountcalc: &AnimationAmountCalc,
+) -> (usize, usize, KeyFrameCurveValue) {
+    let (pre, next) = get_pre_next_frame_index(frames, target_frame);
+    let frame1 = frames[pre];
+    let frame2 = frames[next];
+
+    let amount = if frame1 == frame2 {
+        0.0
+    } else {
+        KeyFrameCurveValue::clamp(
+            amountcalc.calc(
+                (target_frame - frame1)
+                / (frame2 - frame1)
+            ),
+            0.,
+            1.,
+        )
+    };
+
+    (pre, next, amount)
+}
+
+/// 同步逐关键帧数据, from 为 None 时在 to 处插入默认数据, 否则将 from 处的数据移动到 to
+fn update_key_data<D>(data: &mut Vec<D>, from: Option<usize>, to: usize, default: D) {
+    let item = match from {
+        Some(from) => data.remove(from),
+        None => default,
+    };
+    data.insert(to, item);
+}
+
+/// 曲线关键帧 - 在有序帧数组中插入帧位置, 相同位置的帧插入在已有帧之后
 ///
-/// * [framecurve] - 目标曲线
+/// * [frames] - 帧数组
 /// * [frame] - 帧位置
-/// * [value] - 帧数值
 ///
-pub fn curve_frame_index(frames: &mut Vec<FrameIndex>, frame: FrameIndex) -> (usize, FrameIndex, FrameIndex) {
-    let index = frames.binary_search(&frame).unwrap_or_else(|x| x);
+/// 返回 (插入序号, 最小帧, 最大帧)
+pub fn curve_frame_index(frames: &mut Vec<KeyFrameCurveValue>, frame: KeyFrameCurveValue) -> (usize, KeyFrameCurveValue, KeyFrameCurveValue) {
+    let index = frames.partition_point(|item| *item <= frame);
     frames.insert(index, frame);
 
     let len = frames.len();
@@ -0,0 +1,14 @@
+use crate::{curve::{frame_curve::{ECurvePayload, FrameCurve}, frame::{FrameDataValue, KeyFrameCurveValue}}, amount::AnimationAmountCalc, noise::fractal_noise};
+
+
+/// 噪声不依赖曲线进度, amountcalc 不参与计算
+pub fn interplate_noise<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, _amountcalc: &AnimationAmountCalc) -> T {
+    let ECurvePayload::Noise(param) = &curve.payload else {
+        return curve.value_offset.clone().unwrap();
+    };
+
+    let seconds = target_frame / curve.design_frame_per_second as KeyFrameCurveValue;
+    let amount = fractal_noise(param.seed, seconds * param.frequency, param.octaves) * param.amplitude;
+
+    curve.value_offset.as_ref().unwrap().append(curve.value_scalar.as_ref().unwrap(), amount)
+}
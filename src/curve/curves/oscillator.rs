@@ -0,0 +1,55 @@
+use crate::{curve::{frame_curve::{ECurvePayload, FrameCurve}, frame::{FrameDataValue, KeyFrameCurveValue}}, amount::AnimationAmountCalc};
+
+#[cfg(feature = "amount_f32")]
+use std::f32::consts::PI;
+#[cfg(feature = "amount_f64")]
+use std::f64::consts::PI;
+
+/// 周期波形, 一个周期内输出范围 [-1, 1], 起点为 0 (Square 为 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EWaveform {
+    Sine,
+    Triangle,
+    Square,
+    Sawtooth,
+}
+
+impl EWaveform {
+    /// 获取波形函数, 输入为周期内位置 [0, 1)
+    pub fn get_wave_call(waveform: EWaveform) -> fn(KeyFrameCurveValue) -> KeyFrameCurveValue {
+        match waveform {
+            EWaveform::Sine         => wave_sine,
+            EWaveform::Triangle     => wave_triangle,
+            EWaveform::Square       => wave_square,
+            EWaveform::Sawtooth     => wave_sawtooth,
+        }
+    }
+}
+
+pub fn wave_sine(x: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    KeyFrameCurveValue::sin(2. * PI * x)
+}
+pub fn wave_triangle(x: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    1. - 4. * KeyFrameCurveValue::abs((x + 0.25).rem_euclid(1.) - 0.5)
+}
+pub fn wave_square(x: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    if x < 0.5 { 1. } else { -1. }
+}
+pub fn wave_sawtooth(x: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    2. * (x + 0.5).rem_euclid(1.) - 1.
+}
+
+/// 周期曲线不依赖曲线进度, amountcalc 不参与计算
+pub fn interplate_oscillator<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, _amountcalc: &AnimationAmountCalc) -> T {
+    let ECurvePayload::Oscillator { wave, args: [period, phase, amplitude, offset], .. } = curve.payload else {
+        return curve.value_offset.clone().unwrap();
+    };
+
+    let amount = if period <= 0. {
+        offset
+    } else {
+        offset + amplitude * wave((target_frame / period + phase).rem_euclid(1.))
+    };
+
+    curve.value_offset.as_ref().unwrap().append(curve.value_scalar.as_ref().unwrap(), amount)
+}
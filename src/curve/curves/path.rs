@@ -0,0 +1,104 @@
+use crate::{amount::AnimationAmountCalc, curve::frame::{CurveFrameValue, FrameDataValue, FrameValueDistance, KeyFrameCurveValue}};
+
+use super::{EdgeSlope, FrameCurve};
+
+/// 路径每段的弧长采样数
+pub const PATH_SEGMENT_SAMPLES: usize = 16;
+
+pub fn interplate_path<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let (segment, amount) = path_segment_amount(curve, target_frame, amountcalc);
+    path_point(&curve.cubic_spline_values, segment, amount)
+}
+
+/// 路径在 target_frame 处的单位切线方向
+pub fn interplate_path_tangent<T: FrameDataValue + FrameValueDistance>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> T {
+    let (segment, amount) = path_segment_amount(curve, target_frame, amountcalc);
+    let values = &curve.cubic_spline_values;
+
+    let step = 0.001;
+    let value1 = path_point(values, segment, KeyFrameCurveValue::max(amount - step, 0.));
+    let value2 = path_point(values, segment, KeyFrameCurveValue::min(amount + step, 1.));
+
+    // delta = value2 - value1, 再缩放到单位长度
+    let delta = value2.append(&value1, -1.);
+    let length = value1.distance(&value2);
+    if length <= KeyFrameCurveValue::EPSILON {
+        delta.append(&delta, -1.)
+    } else {
+        delta.append(&delta, 1. / length - 1.)
+    }
+}
+
+/// 线性外插 - 沿端点处的切线方向, 保持路径上的匀速
+pub fn edge_slope_path<T: FrameDataValue + FrameValueDistance>(curve: &FrameCurve<T>, end: bool) -> EdgeSlope<T> {
+    let frame = if end { curve.max_frame } else { curve.min_frame };
+    let direction = interplate_path_tangent(curve, frame, &AnimationAmountCalc::default());
+    Some((direction, curve.path_length() / curve.frame_number))
+}
+
+/// 由弧长表将进度映射为 (路径段, 段内参数)
+pub fn path_segment_amount<T: FrameDataValue>(curve: &FrameCurve<T>, target_frame: KeyFrameCurveValue, amountcalc: &AnimationAmountCalc) -> (usize, KeyFrameCurveValue) {
+    let lengths = &curve.path_lengths;
+    let count = curve.cubic_spline_values.len();
+    if count < 2 || lengths.len() < 2 {
+        return (0, 0.);
+    }
+
+    let amount = if curve.frame_number <= 0. {
+        0.
+    } else {
+        KeyFrameCurveValue::clamp(
+            amountcalc.calc(
+                (target_frame - curve.min_frame)
+                / curve.frame_number
+            ),
+            0.,
+            1.,
+        )
+    };
+
+    let total = lengths[lengths.len() - 1];
+    let distance = amount * total;
+
+    let index = lengths.partition_point(|length| *length <= distance);
+    let index = usize::clamp(index, 1, lengths.len() - 1);
+    let (length1, length2) = (lengths[index - 1], lengths[index]);
+    let local = if length2 > length1 { (distance - length1) / (length2 - length1) } else { 0. };
+
+    let parameter = ((index - 1) as KeyFrameCurveValue + local) / PATH_SEGMENT_SAMPLES as KeyFrameCurveValue;
+    let segment = usize::min(parameter.floor() as usize, count - 2);
+
+    (segment, KeyFrameCurveValue::clamp(parameter - segment as KeyFrameCurveValue, 0., 1.))
+}
+
+/// 计算路径累计弧长表, 每段 PATH_SEGMENT_SAMPLES 个采样
+pub fn path_arc_lengths<T: FrameDataValue + FrameValueDistance>(values: &[CurveFrameValue<T>]) -> Vec<KeyFrameCurveValue> {
+    let mut lengths = vec![];
+    if values.len() < 2 {
+        return lengths;
+    }
+
+    lengths.push(0.);
+    let mut total = 0.;
+    for segment in 0..values.len() - 1 {
+        let mut last = values[segment].value().clone();
+        for sample in 1..=PATH_SEGMENT_SAMPLES {
+            let point = path_point(values, segment, sample as KeyFrameCurveValue / PATH_SEGMENT_SAMPLES as KeyFrameCurveValue);
+            total += last.distance(&point);
+            lengths.push(total);
+            last = point;
+        }
+    }
+
+    lengths
+}
+
+fn path_point<T: FrameDataValue>(values: &[CurveFrameValue<T>], segment: usize, amount: KeyFrameCurveValue) -> T {
+    if values.len() < 2 {
+        return values[0].value().clone();
+    }
+
+    let key1 = &values[segment];
+    let key2 = &values[segment + 1];
+    T::hermite(key1.value(), key1.outtangent(), key2.value(), key2.intangent(), amount, 1.)
+}
@@ -0,0 +1,82 @@
+use crate::curve::frame::{FrameDataValue, KeyFrameCurveValue};
+
+use super::FrameCurve;
+
+/// 曲线区间外的求值方式 (Pre/Post Infinity)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ECurveWrapMode {
+    /// 保持端点数值
+    Constant,
+    /// 沿端点处的切线方向线性外插
+    Linear,
+    /// 循环
+    Loop,
+    /// 往返循环
+    PingPong,
+    /// 循环, 每个周期累加 首尾数值差
+    CycleWithOffset,
+}
+
+/// 将目标帧映射回曲线区间 [min_frame, max_frame]
+///
+/// 返回 (映射后的帧, 经过的周期数), 周期数在区间之前为负
+pub fn wrap_frame(mode: ECurveWrapMode, target_frame: KeyFrameCurveValue, min_frame: KeyFrameCurveValue, max_frame: KeyFrameCurveValue) -> (KeyFrameCurveValue, KeyFrameCurveValue) {
+    let frame_number = max_frame - min_frame;
+    if frame_number <= 0. {
+        return (min_frame, 0.);
+    }
+
+    match mode {
+        ECurveWrapMode::Constant | ECurveWrapMode::Linear => {
+            (KeyFrameCurveValue::clamp(target_frame, min_frame, max_frame), 0.)
+        },
+        ECurveWrapMode::Loop | ECurveWrapMode::CycleWithOffset => {
+            let offset = target_frame - min_frame;
+            let cycles = (offset / frame_number).floor();
+            (min_frame + (offset - cycles * frame_number), cycles)
+        },
+        ECurveWrapMode::PingPong => {
+            let offset = target_frame - min_frame;
+            let cycles = (offset / frame_number).floor();
+            let local = offset - cycles * frame_number;
+            if cycles.rem_euclid(2.) >= 1. {
+                (max_frame - local, cycles)
+            } else {
+                (min_frame + local, cycles)
+            }
+        },
+    }
+}
+
+/// 线性外插时曲线端点处的斜率 - (方向, 每帧的倍数), 外插数值为 端点数值.append(方向, 倍数 * 距端点的帧数)
+///
+/// None 表示水平外插, 保持端点数值
+pub type EdgeSlope<T> = Option<(T, KeyFrameCurveValue)>;
+
+/// 水平外插 - 步进、常数以及没有端点切线的曲线
+pub fn edge_slope_flat<T: FrameDataValue>(_curve: &FrameCurve<T>, _end: bool) -> EdgeSlope<T> {
+    None
+}
+
+/// 起点 (end 为 false) 或终点所在关键帧段的 (前帧序号, 后帧序号), 少于两个关键帧时为 None
+pub fn edge_segment(frames: &[KeyFrameCurveValue], end: bool) -> Option<(usize, usize)> {
+    let len = frames.len();
+    if len < 2 {
+        None
+    } else if end {
+        Some((len - 2, len - 1))
+    } else {
+        Some((0, 1))
+    }
+}
+
+/// 线性段的斜率, 差值由 interpolate 到达后一关键帧后求得, 保留类型自身的插值路径 (如角度的最短弧)
+///
+/// * [frame_delta] - 两关键帧间隔的帧数
+pub fn segment_slope<T: FrameDataValue>(value1: &T, value2: &T, frame_delta: KeyFrameCurveValue) -> EdgeSlope<T> {
+    if frame_delta <= KeyFrameCurveValue::EPSILON {
+        return None;
+    }
+
+    Some((value1.interpolate(value2, 1.).append(value1, -1.), 1. / frame_delta))
+}
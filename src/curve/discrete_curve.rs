@@ -0,0 +1,114 @@
+//!
+//! 离散关键帧曲线 - 数值不可插值 (bool, 枚举状态, 字符串, 贴图句柄等), 只要求 Clone
+use super::{curves::{curve_frame_index, get_pre_next_frame_index, wrap_frame, ECurveWrapMode, EFrameStepMode}, frame::{KeyFrameCurveValue, KeyFrameTime}, FramePerSecond};
+
+/// 离散关键帧曲线, 求值时保持当前生效的关键帧数值
+#[derive(Debug, Clone)]
+pub struct DiscreteCurve<T: Clone> {
+    /// 设计每秒多少帧
+    pub design_frame_per_second: FramePerSecond,
+    /// 两关键帧之间的取值方式
+    pub step_mode: EFrameStepMode,
+    /// 曲线区间之前的求值方式 (Linear 同 Constant, CycleWithOffset 同 Loop)
+    pub pre_wrap_mode: ECurveWrapMode,
+    /// 曲线区间之后的求值方式
+    pub post_wrap_mode: ECurveWrapMode,
+    /// 关键帧位置
+    pub frames: Vec<KeyFrameCurveValue>,
+    /// 关键帧数值
+    pub values: Vec<T>,
+    /// 最小帧
+    pub min_frame: KeyFrameCurveValue,
+    /// 最大帧
+    pub max_frame: KeyFrameCurveValue,
+    /// 帧数
+    pub frame_number: KeyFrameCurveValue,
+}
+
+impl<T: Clone> DiscreteCurve<T> {
+    /// 离散曲线 - 默认保持前一关键帧数值
+    pub fn new(design_frame_per_second: FramePerSecond) -> Self {
+        Self {
+            design_frame_per_second,
+            step_mode: EFrameStepMode::Previous,
+            pre_wrap_mode: ECurveWrapMode::Constant,
+            post_wrap_mode: ECurveWrapMode::Constant,
+            frames: vec![],
+            values: vec![],
+            min_frame: 0.,
+            max_frame: 0.,
+            frame_number: 0.,
+        }
+    }
+    /// 离散曲线 - 指定两关键帧之间的取值方式
+    pub fn new_with_mode(design_frame_per_second: FramePerSecond, mode: EFrameStepMode) -> Self {
+        let mut curve = Self::new(design_frame_per_second);
+        curve.step_mode = mode;
+        curve
+    }
+    /// 添加关键帧, 相同位置的关键帧插入在已有关键帧之后
+    ///
+    /// * [frame] - 帧位置
+    /// * [value] - 帧数值
+    ///
+    pub fn curve_frame(&mut self, frame: impl KeyFrameTime, value: T) {
+        let (index, min, max) = curve_frame_index(&mut self.frames, frame.frame_time());
+        self.values.insert(index, value);
+
+        self.min_frame = min;
+        self.max_frame = max;
+        self.frame_number = max - min;
+    }
+    /// 设置曲线区间外的求值方式
+    pub fn set_wrap_mode(&mut self, pre: ECurveWrapMode, post: ECurveWrapMode) {
+        self.pre_wrap_mode = pre;
+        self.post_wrap_mode = post;
+    }
+    pub fn size(&self) -> usize {
+        1 + 1 + 1 + 2 + 4 * 3
+        + std::mem::size_of::<KeyFrameCurveValue>() * self.frames.len()
+        + std::mem::size_of::<T>() * self.values.len()
+    }
+    /// 求值, 无关键帧时返回 None
+    ///
+    /// * [target_frame] - 目标时间 (秒)
+    ///
+    pub fn interple(&self, target_frame: KeyFrameCurveValue) -> Option<&T> {
+        let target_frame = target_frame * self.design_frame_per_second as KeyFrameCurveValue;
+        self.key_index(target_frame).map(|index| &self.values[index])
+    }
+    /// 目标帧处生效的关键帧序号, 无关键帧时返回 None
+    ///
+    /// * [target_frame] - 目标帧
+    ///
+    pub fn key_index(&self, target_frame: KeyFrameCurveValue) -> Option<usize> {
+        if self.frames.is_empty() {
+            return None;
+        }
+
+        let mode = if target_frame < self.min_frame {
+            self.pre_wrap_mode
+        } else if target_frame > self.max_frame {
+            self.post_wrap_mode
+        } else {
+            ECurveWrapMode::Constant
+        };
+        let (target_frame, _) = wrap_frame(mode, target_frame, self.min_frame, self.max_frame);
+
+        let (pre, next) = get_pre_next_frame_index(&self.frames, target_frame);
+        let (frame1, frame2) = (self.frames[pre], self.frames[next]);
+        if pre == next || target_frame <= frame1 {
+            return Some(pre);
+        }
+
+        let index = match self.step_mode {
+            EFrameStepMode::Previous => pre,
+            EFrameStepMode::Next => next,
+            EFrameStepMode::Nearest => {
+                if (target_frame - frame1) / (frame2 - frame1) < 0.5 { pre } else { next }
+            },
+        };
+
+        Some(index)
+    }
+}
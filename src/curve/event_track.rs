@@ -0,0 +1,146 @@
+//!
+//! 动画事件轨道 - 在帧位置上放置事件 (脚步声, 粒子发射, 逻辑回调等), 查询两次更新之间经过的事件
+use super::{curves::{curve_frame_index, ECurveWrapMode}, frame::{KeyFrameCurveValue, KeyFrameTime}, FramePerSecond};
+
+/// 动画事件轨道
+///
+/// 查询使用未经循环映射的累计播放时间, 因此循环、倒放与大跨度跳转都可以正确处理:
+/// 正向播放经过区间 (previous, current], 倒放经过区间 [current, previous)
+#[derive(Debug, Clone)]
+pub struct EventTrack<E> {
+    /// 设计每秒多少帧
+    pub design_frame_per_second: FramePerSecond,
+    /// 区间外的播放方式, Loop / CycleWithOffset 循环, PingPong 往返循环, 其他不循环
+    pub wrap_mode: ECurveWrapMode,
+    /// 事件帧位置
+    pub frames: Vec<KeyFrameCurveValue>,
+    /// 事件
+    pub events: Vec<E>,
+    /// 播放区间起始帧
+    pub min_frame: KeyFrameCurveValue,
+    /// 播放区间结束帧
+    pub max_frame: KeyFrameCurveValue,
+}
+
+impl<E> EventTrack<E> {
+    /// 事件轨道 - 播放区间默认从第 0 帧到最后一个事件
+    pub fn new(design_frame_per_second: FramePerSecond, wrap_mode: ECurveWrapMode) -> Self {
+        Self {
+            design_frame_per_second,
+            wrap_mode,
+            frames: vec![],
+            events: vec![],
+            min_frame: 0.,
+            max_frame: 0.,
+        }
+    }
+    /// 添加事件, 相同位置的事件按添加顺序触发; 播放区间扩展到包含该事件
+    ///
+    /// * [frame] - 帧位置
+    /// * [event] - 事件
+    ///
+    pub fn add_event(&mut self, frame: impl KeyFrameTime, event: E) {
+        let frame = frame.frame_time();
+        let (index, _, _) = curve_frame_index(&mut self.frames, frame);
+        self.events.insert(index, event);
+
+        self.min_frame = KeyFrameCurveValue::min(self.min_frame, frame);
+        self.max_frame = KeyFrameCurveValue::max(self.max_frame, frame);
+    }
+    /// 设置播放区间, 通常与动画曲线的帧范围一致
+    pub fn set_range(&mut self, min_frame: impl KeyFrameTime, max_frame: impl KeyFrameTime) {
+        self.min_frame = min_frame.frame_time();
+        self.max_frame = max_frame.frame_time();
+    }
+    pub fn size(&self) -> usize {
+        2 + 1 + 4 * 2
+        + std::mem::size_of::<KeyFrameCurveValue>() * self.frames.len()
+        + std::mem::size_of::<E>() * self.events.len()
+    }
+    /// 两次更新之间经过的事件, 按播放顺序排列
+    ///
+    /// * [previous] - 上次更新的累计播放时间 (秒)
+    /// * [current] - 本次更新的累计播放时间 (秒)
+    ///
+    pub fn crossed_events(&self, previous: KeyFrameCurveValue, current: KeyFrameCurveValue) -> Vec<&E> {
+        self.crossed(previous, current).into_iter().map(|index| &self.events[index]).collect()
+    }
+    /// 两次更新之间经过的事件序号, 按播放顺序排列, 跨越多个周期时同一事件会多次出现
+    ///
+    /// 大跨度跳转时首尾不完整的周期之间最多保留一个完整周期 (往返循环为一次往返), 其余周期的事件不再重复
+    ///
+    /// * [previous] - 上次更新的累计播放时间 (秒)
+    /// * [current] - 本次更新的累计播放时间 (秒)
+    ///
+    pub fn crossed(&self, previous: KeyFrameCurveValue, current: KeyFrameCurveValue) -> Vec<usize> {
+        let design_frame_per_second = self.design_frame_per_second as KeyFrameCurveValue;
+        self.crossed_frames(previous * design_frame_per_second, current * design_frame_per_second)
+    }
+    /// 同 crossed, 参数为累计播放帧
+    pub fn crossed_frames(&self, previous: KeyFrameCurveValue, current: KeyFrameCurveValue) -> Vec<usize> {
+        if self.frames.is_empty() || previous == current {
+            return vec![];
+        }
+
+        let forward = current > previous;
+        let (mut low, mut high) = if forward { (previous, current) } else { (current, previous) };
+
+        let mut result: Vec<(KeyFrameCurveValue, usize)> = vec![];
+        let frame_number = self.max_frame - self.min_frame;
+        let looping = matches!(self.wrap_mode, ECurveWrapMode::Loop | ECurveWrapMode::PingPong | ECurveWrapMode::CycleWithOffset);
+
+        if looping && frame_number > 0. {
+            // 去掉多余的完整周期, 保持终点所在的相位不变
+            let period = if self.wrap_mode == ECurveWrapMode::PingPong { frame_number * 2. } else { frame_number };
+            let skipped = ((high - low) / period).floor() - 1.;
+            if skipped > 0. {
+                if forward {
+                    high -= skipped * period;
+                } else {
+                    low += skipped * period;
+                }
+            }
+        }
+
+        let contains = |frame: KeyFrameCurveValue| {
+            if forward { low < frame && frame <= high } else { low <= frame && frame < high }
+        };
+
+        if !looping || frame_number <= 0. {
+            for (index, frame) in self.frames.iter().enumerate() {
+                if contains(*frame) {
+                    result.push((*frame, index));
+                }
+            }
+        } else {
+            // 前一周期末尾的事件与当前周期起点重合
+            let cycle_start = ((low - self.min_frame) / frame_number).floor() as i64 - 1;
+            let cycle_end = ((high - self.min_frame) / frame_number).floor() as i64;
+            for cycle in cycle_start..=cycle_end {
+                let offset = self.min_frame + cycle as KeyFrameCurveValue * frame_number;
+                let reversed = self.wrap_mode == ECurveWrapMode::PingPong && cycle.rem_euclid(2) == 1;
+                for (index, frame) in self.frames.iter().enumerate() {
+                    let local = frame - self.min_frame;
+                    let frame = if reversed {
+                        // 往返的折返点已在相邻的正向周期中触发
+                        if local <= 0. || local >= frame_number {
+                            continue;
+                        }
+                        offset + frame_number - local
+                    } else {
+                        offset + local
+                    };
+                    if contains(frame) {
+                        result.push((frame, index));
+                    }
+                }
+            }
+        }
+
+        result.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if !forward {
+            result.reverse();
+        }
+        result.into_iter().map(|(_, index)| index).collect()
+    }
+}
@@ -1,7 +1,7 @@
 
 use std::ops::Add;
 
-use super::ErrorCurve;
+use super::{ErrorCurve, ETangentMode, EWeightedMode, FrameValueRounding, RoundNearest};
 
 /// 关键帧曲线数值类型
 pub use crate::types::KeyFrameCurveValue;
@@ -12,14 +12,32 @@ pub use crate::types::KeyFrameDataType;
 pub struct CurveFrameValue<T: FrameDataValue> {
     /// 帧数据值
     value: T,
-    args: [T; 2]
+    args: [T; 2],
+    /// 切线模式
+    mode: ETangentMode,
+    /// 切线权重 [in, out], 为相邻区间时长的比例
+    weights: [KeyFrameCurveValue; 2],
+    /// 切线权重模式
+    weighted_mode: EWeightedMode,
 }
 
 impl<T: FrameDataValue> CurveFrameValue<T> {
     pub fn new(value: T, args: [T; 2]) -> Self {
         CurveFrameValue {
             value,
-            args
+            args,
+            mode: ETangentMode::Free,
+            weights: [EWeightedMode::DEFAULT_WEIGHT; 2],
+            weighted_mode: EWeightedMode::None,
+        }
+    }
+    pub fn new_with_mode(value: T, args: [T; 2], mode: ETangentMode) -> Self {
+        CurveFrameValue {
+            value,
+            args,
+            mode,
+            weights: [EWeightedMode::DEFAULT_WEIGHT; 2],
+            weighted_mode: EWeightedMode::None,
         }
     }
     pub fn value(&self) -> &T {
@@ -31,23 +49,90 @@ impl<T: FrameDataValue> CurveFrameValue<T> {
     pub fn outtangent(&self) -> &T {
         &self.args[1]
     }
+    pub fn mode(&self) -> ETangentMode {
+        self.mode
+    }
+    pub fn weighted_mode(&self) -> EWeightedMode {
+        self.weighted_mode
+    }
+    /// In 切线权重, 未启用时为 1/3
+    pub fn inweight(&self) -> KeyFrameCurveValue {
+        if self.weighted_mode.has_in() { self.weights[0] } else { EWeightedMode::DEFAULT_WEIGHT }
+    }
+    /// Out 切线权重, 未启用时为 1/3
+    pub fn outweight(&self) -> KeyFrameCurveValue {
+        if self.weighted_mode.has_out() { self.weights[1] } else { EWeightedMode::DEFAULT_WEIGHT }
+    }
+    /// 设置切线权重, 权重限制在 [0, 1]
+    pub fn set_weights(&mut self, inweight: KeyFrameCurveValue, outweight: KeyFrameCurveValue, weighted_mode: EWeightedMode) {
+        self.weights = [KeyFrameCurveValue::clamp(inweight, 0., 1.), KeyFrameCurveValue::clamp(outweight, 0., 1.)];
+        self.weighted_mode = weighted_mode;
+    }
+    pub(crate) fn set_value(&mut self, value: T) {
+        self.value = value;
+    }
+    pub(crate) fn set_tangents(&mut self, intangent: T, outtangent: T) {
+        self.args = [intangent, outtangent];
+    }
 }
 
 pub trait FrameValueScale {
     fn scale(&self, rhs: KeyFrameCurveValue) -> Self;
+    /// 线性插值, 默认由 scale 组合; 整数类型重写为只取整一次
+    fn scale_interpolate(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self where Self: Sized + Add<Output = Self> {
+        self.scale(1.0 - amount) + rhs.scale(amount)
+    }
+    /// 叠加, 默认由 scale 组合; 整数类型重写为只取整一次
+    fn scale_append(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self where Self: Sized + Clone + Add<Output = Self> {
+        self.clone() + rhs.scale(amount)
+    }
+    /// Hermite 插值, 默认由 scale 组合; 整数类型重写为只取整一次
+    fn scale_hermite(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self where Self: Sized + Add<Output = Self> {
+        let _1 = 1 as KeyFrameCurveValue;
+        let _2 = 2 as KeyFrameCurveValue;
+        let _3 = 3 as KeyFrameCurveValue;
+
+        let squared = amount * amount;
+        let cubed = amount * squared;
+        let part1 = ((_2 * cubed) - (_3 * squared)) + _1;
+        let part2 = (-_2 * cubed) + (_3 * squared);
+        let part3 = (cubed - (_2 * squared)) + amount;
+        let part4 = cubed - squared;
+
+        (((value1.scale(part1)) + (value2.scale(part2))) + (tangent1.scale(part3 * frame_delta))) + (tangent2.scale(part4 * frame_delta))
+    }
+}
+
+/// 关键帧时间位置 (帧), 整数帧与小数帧均可
+pub trait KeyFrameTime: Copy {
+    fn frame_time(self) -> KeyFrameCurveValue;
+}
+
+/// 帧数据之间的距离 - 用于按距离参数化的曲线 (如 Catmull-Rom 的 Centripetal/Chordal 模式)
+pub trait FrameValueDistance {
+    fn distance(&self, rhs: &Self) -> KeyFrameCurveValue;
+}
+
+/// 带透明度的帧数据 - 用于 Gradient 曲线分别插值颜色与透明度
+pub trait FrameValueAlpha {
+    fn alpha(&self) -> KeyFrameCurveValue;
+    fn with_alpha(&self, alpha: KeyFrameCurveValue) -> Self;
+    /// 不透明白色 - 渐变没有颜色关键帧时的颜色 (同 Unity)
+    fn white() -> Self;
+}
+
+/// 帧数据限制在 [min, max] 内 - 用于 Limits 修改器
+pub trait FrameValueLimit {
+    fn limit(&self, min: &Self, max: &Self) -> Self;
 }
 
 /// 动画数据类型Idx分配器 - 使用 usize, 便于用Vec存储类型
+#[derive(Default)]
 pub struct KeyFrameDataTypeAllocator {
     counter: KeyFrameDataType,
 }
 
 impl KeyFrameDataTypeAllocator {
-    pub fn default() -> Self {
-        Self {
-            counter: 0
-        }
-    }
     pub fn alloc(
         &mut self,
     ) -> Result<KeyFrameDataType, ErrorCurve> {
@@ -70,24 +155,13 @@ pub trait FrameDataValue: Clone {
 
 impl<T: Clone + FrameValueScale + Add<Output = Self>> FrameDataValue for T {
     fn interpolate(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
-        self.scale(1.0 - amount) + rhs.scale(amount)
+        self.scale_interpolate(rhs, amount)
     }
     fn hermite(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self {
-        let _1 = 1 as KeyFrameCurveValue;
-        let _2 = 2 as KeyFrameCurveValue;
-        let _3 = 3 as KeyFrameCurveValue;
-
-        let squared = amount * amount;
-        let cubed = amount * squared;
-        let part1 = ((_2 * cubed) - (_3 * squared)) + _1;
-        let part2 = (-_2 * cubed) + (_3 * squared);
-        let part3 = (cubed - (_2 * squared)) + amount;
-        let part4 = cubed - squared;
-
-        return (((value1.scale(part1)) + (value2.scale(part2))) + (tangent1.scale(part3 * frame_delta))) + (tangent2.scale(part4 * frame_delta));
+        T::scale_hermite(value1, tangent1, value2, tangent2, amount, frame_delta)
     }
     fn append(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
-        self.clone() + rhs.scale(amount)
+        self.scale_append(rhs, amount)
     }
     fn size() -> usize {
         8
@@ -108,37 +182,104 @@ impl FrameValueScale for f64 {
     }
 }
 
-/// u8
-impl FrameValueScale for u8 {
-    fn scale(&self, rhs: KeyFrameCurveValue) -> Self {
-        (*self as KeyFrameCurveValue * rhs) as Self
-    }
+/// 整数帧数据, 在浮点数中计算后按取整方式 P 取整一次, 超出范围时饱和 (NaN 为 0)
+/// 8/16 位整数使用 KeyFrameCurveValue 计算, 更宽的整数使用 f64 计算以保留精度
+pub trait FrameValueInteger: Copy {
+    fn interpolate_rounded<P: FrameValueRounding>(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self;
+    fn append_rounded<P: FrameValueRounding>(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self;
+    fn hermite_rounded<P: FrameValueRounding>(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self;
 }
 
-/// u16
-impl FrameValueScale for u16 {
-    fn scale(&self, rhs: KeyFrameCurveValue) -> Self {
-        (*self as KeyFrameCurveValue * rhs) as Self
-    }
+macro_rules! impl_integer_frame_value {
+    ($($ty:ty => $calc:ty),*) => {
+        $(
+            impl FrameValueInteger for $ty {
+                fn interpolate_rounded<P: FrameValueRounding>(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+                    let value = (*self as $calc).scale_interpolate(&(*rhs as $calc), amount);
+                    P::round(value as f64) as Self
+                }
+                fn append_rounded<P: FrameValueRounding>(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+                    let value = (*self as $calc).scale_append(&(*rhs as $calc), amount);
+                    P::round(value as f64) as Self
+                }
+                fn hermite_rounded<P: FrameValueRounding>(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self {
+                    let value = <$calc>::scale_hermite(
+                        &(*value1 as $calc), &(*tangent1 as $calc),
+                        &(*value2 as $calc), &(*tangent2 as $calc),
+                        amount, frame_delta,
+                    );
+                    P::round(value as f64) as Self
+                }
+            }
+
+            /// 四舍五入取整, 其他取整方式使用 Rounded<T, P>
+            impl FrameValueScale for $ty {
+                fn scale(&self, rhs: KeyFrameCurveValue) -> Self {
+                    RoundNearest::round((*self as $calc).scale(rhs) as f64) as Self
+                }
+                fn scale_interpolate(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+                    self.interpolate_rounded::<RoundNearest>(rhs, amount)
+                }
+                fn scale_append(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+                    self.append_rounded::<RoundNearest>(rhs, amount)
+                }
+                fn scale_hermite(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self {
+                    Self::hermite_rounded::<RoundNearest>(value1, tangent1, value2, tangent2, amount, frame_delta)
+                }
+            }
+        )*
+    };
 }
 
-/// u32
-impl FrameValueScale for u32 {
-    fn scale(&self, rhs: KeyFrameCurveValue) -> Self {
-        (*self as KeyFrameCurveValue * rhs) as Self
-    }
+impl_integer_frame_value!(
+    u8 => KeyFrameCurveValue, u16 => KeyFrameCurveValue, u32 => f64, u64 => f64, usize => f64,
+    i8 => KeyFrameCurveValue, i16 => KeyFrameCurveValue, i32 => f64, i64 => f64
+);
+
+macro_rules! impl_frame_value_distance {
+    ($($ty:ty),*) => {
+        $(
+            impl FrameValueDistance for $ty {
+                fn distance(&self, rhs: &Self) -> KeyFrameCurveValue {
+                    (*self as KeyFrameCurveValue - *rhs as KeyFrameCurveValue).abs()
+                }
+            }
+        )*
+    };
 }
 
-/// u64
-impl FrameValueScale for u64 {
-    fn scale(&self, rhs: KeyFrameCurveValue) -> Self {
-        (*self as KeyFrameCurveValue * rhs) as Self
-    }
+impl_frame_value_distance!(f32, f64, u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+macro_rules! impl_frame_value_limit {
+    ($($ty:ty),*) => {
+        $(
+            impl FrameValueLimit for $ty {
+                fn limit(&self, min: &Self, max: &Self) -> Self {
+                    if *self < *min {
+                        *min
+                    } else if *self > *max {
+                        *max
+                    } else {
+                        *self
+                    }
+                }
+            }
+        )*
+    };
 }
 
-/// usize
-impl FrameValueScale for usize {
-    fn scale(&self, rhs: KeyFrameCurveValue) -> Self {
-        (*self as KeyFrameCurveValue * rhs) as Self
-    }
+impl_frame_value_limit!(f32, f64, u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+macro_rules! impl_key_frame_time {
+    ($($ty:ty),*) => {
+        $(
+            impl KeyFrameTime for $ty {
+                fn frame_time(self) -> KeyFrameCurveValue {
+                    self as KeyFrameCurveValue
+                }
+            }
+        )*
+    };
 }
+
+impl_key_frame_time!(f32, f64, u8, u16, u32, u64, usize, i8, i16, i32, i64);
@@ -13,7 +13,18 @@ pub enum ErrorCurve {
 mod frame;
 mod frame_curve;
 mod curves;
+mod tangent;
+mod values;
+mod modifier;
+mod discrete_curve;
+mod event_track;
+mod composite_curve;
 
 pub use frame::*;
+pub use tangent::*;
+pub use values::*;
+pub use modifier::*;
+pub use discrete_curve::*;
+pub use event_track::*;
+pub use composite_curve::*;
 pub use frame_curve::*;
-pub use curves::*;
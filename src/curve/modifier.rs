@@ -0,0 +1,142 @@
+//!
+//! 曲线修改器 (类 Blender F-Curve Modifiers)
+use crate::noise::{NoiseParam, fractal_noise};
+
+use super::{frame::{FrameDataValue, FrameValueLimit, KeyFrameCurveValue}, curves::{ECurveWrapMode, wrap_frame}, FramePerSecond};
+
+/// 修改器类型
+///
+/// 时间修改器 (Cycles, Stepped, LimitTime) 在曲线求值前按修改器栈的逆序修改目标帧,
+/// 数值修改器在曲线求值后按修改器栈的顺序修改数值
+#[derive(Debug, Clone)]
+pub enum ECurveModifier<T> {
+    /// 多项式生成器, 数值为 Σ coefficients[i] * t^i, t 为秒
+    /// additive 为 true 时叠加到原数值上, 否则替换原数值
+    Generator { coefficients: Vec<T>, additive: bool },
+    /// 在曲线区间外循环, 仅 Loop / PingPong / CycleWithOffset 生效
+    Cycles { before: ECurveWrapMode, after: ECurveWrapMode },
+    /// 叠加噪声, 数值为 value + scalar * noise
+    Noise { param: NoiseParam, scalar: T },
+    /// 限制目标帧范围
+    LimitTime { min: KeyFrameCurveValue, max: KeyFrameCurveValue },
+    /// 限制数值范围
+    LimitValue { min: T, max: T, limit: fn(&T, &T, &T) -> T },
+    /// 目标帧按 step 帧量化, offset 为量化起点
+    Stepped { step: KeyFrameCurveValue, offset: KeyFrameCurveValue },
+    /// 数值以 reference 为中心缩放, 缩放系数由控制点 (帧, 系数) 线性插值得到
+    Envelope { reference: T, points: Vec<(KeyFrameCurveValue, KeyFrameCurveValue)> },
+}
+
+/// 曲线修改器
+#[derive(Debug, Clone)]
+pub struct CurveModifier<T> {
+    pub mode: ECurveModifier<T>,
+    /// 影响权重 [0, 1]
+    pub influence: KeyFrameCurveValue,
+    /// 为 true 时不生效
+    pub mute: bool,
+}
+
+impl<T: FrameDataValue> CurveModifier<T> {
+    pub fn new(mode: ECurveModifier<T>) -> Self {
+        Self {
+            mode,
+            influence: 1.,
+            mute: false,
+        }
+    }
+    pub fn is_time_modifier(&self) -> bool {
+        matches!(self.mode, ECurveModifier::Cycles { .. } | ECurveModifier::Stepped { .. } | ECurveModifier::LimitTime { .. })
+    }
+    /// 时间修改器 - 修改目标帧
+    ///
+    /// * [min_frame, max_frame] - 曲线的帧范围
+    ///
+    pub fn modify_frame(&self, target_frame: KeyFrameCurveValue, min_frame: KeyFrameCurveValue, max_frame: KeyFrameCurveValue) -> KeyFrameCurveValue {
+        let result = match &self.mode {
+            ECurveModifier::Cycles { before, after } => {
+                let mode = if target_frame < min_frame { *before } else if target_frame > max_frame { *after } else { return target_frame };
+                match mode {
+                    ECurveWrapMode::Loop | ECurveWrapMode::PingPong | ECurveWrapMode::CycleWithOffset => {
+                        wrap_frame(mode, target_frame, min_frame, max_frame).0
+                    },
+                    _ => target_frame,
+                }
+            },
+            ECurveModifier::Stepped { step, offset } => {
+                if *step <= 0. {
+                    target_frame
+                } else {
+                    offset + ((target_frame - offset) / step).floor() * step
+                }
+            },
+            ECurveModifier::LimitTime { min, max } => {
+                KeyFrameCurveValue::clamp(target_frame, *min, *max)
+            },
+            _ => target_frame,
+        };
+
+        target_frame + (result - target_frame) * self.influence
+    }
+    /// 数值修改器 - 修改数值
+    ///
+    /// * [target_frame] - 未经时间修改器修改的目标帧
+    ///
+    pub fn modify_value(&self, value: T, target_frame: KeyFrameCurveValue, design_frame_per_second: FramePerSecond) -> T {
+        let seconds = target_frame / design_frame_per_second as KeyFrameCurveValue;
+
+        let result = match &self.mode {
+            ECurveModifier::Generator { coefficients, additive } => {
+                if coefficients.is_empty() {
+                    return value;
+                }
+                let mut power = 1.;
+                let mut result = if *additive { value.clone() } else { coefficients[0].append(&coefficients[0], -1.) };
+                for coefficient in coefficients.iter() {
+                    result = result.append(coefficient, power);
+                    power *= seconds;
+                }
+                result
+            },
+            ECurveModifier::Noise { param, scalar } => {
+                let amount = fractal_noise(param.seed, seconds * param.frequency, param.octaves) * param.amplitude;
+                value.append(scalar, amount)
+            },
+            ECurveModifier::LimitValue { min, max, limit } => {
+                limit(&value, min, max)
+            },
+            ECurveModifier::Envelope { reference, points } => {
+                let scale = envelope_scale(points, target_frame);
+                reference.append(&value.append(reference, -1.), scale)
+            },
+            _ => return value,
+        };
+
+        value.interpolate(&result, self.influence)
+    }
+}
+
+impl<T: FrameDataValue + FrameValueLimit> CurveModifier<T> {
+    /// 创建限制数值范围的修改器
+    pub fn limit_value(min: T, max: T) -> Self {
+        Self::new(ECurveModifier::LimitValue { min, max, limit: T::limit })
+    }
+}
+
+/// Envelope 控制点 (帧, 系数) 在目标帧处的缩放系数, 控制点需按帧升序排列
+fn envelope_scale(points: &[(KeyFrameCurveValue, KeyFrameCurveValue)], target_frame: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    if points.is_empty() {
+        return 1.;
+    }
+
+    let index = points.partition_point(|(frame, _)| *frame <= target_frame);
+    if index == 0 {
+        points[0].1
+    } else if index == points.len() {
+        points[index - 1].1
+    } else {
+        let (frame1, scale1) = points[index - 1];
+        let (frame2, scale2) = points[index];
+        scale1 + (scale2 - scale1) * (target_frame - frame1) / (frame2 - frame1)
+    }
+}
@@ -0,0 +1,124 @@
+//!
+//! 关键帧切线自动计算
+
+use crate::types::KeyFrameCurveValue;
+
+/// 关键帧切线模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ETangentMode {
+    /// 自由切线 - 使用指定的 in/out 切线, 不自动计算
+    Free,
+    /// 自动平滑 - 切线平行于前后相邻关键帧的连线
+    Auto,
+    /// 水平切线
+    Flat,
+    /// 线性 - in/out 切线分别指向前/后相邻关键帧
+    Linear,
+    /// 自动平滑且不过冲 - 数值不超出相邻关键帧的范围
+    Clamped,
+}
+
+/// 关键帧切线权重模式 (类 Unity WeightedMode)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EWeightedMode {
+    /// 不使用权重, in/out 权重均为 1/3
+    None,
+    /// 仅 in 切线使用权重
+    In,
+    /// 仅 out 切线使用权重
+    Out,
+    /// in/out 切线均使用权重
+    Both,
+}
+
+impl EWeightedMode {
+    /// 未启用权重时的默认权重, 此时曲线等价于 hermite 插值
+    pub const DEFAULT_WEIGHT: KeyFrameCurveValue = 1. / 3.;
+
+    pub fn has_in(&self) -> bool {
+        matches!(self, EWeightedMode::In | EWeightedMode::Both)
+    }
+    pub fn has_out(&self) -> bool {
+        matches!(self, EWeightedMode::Out | EWeightedMode::Both)
+    }
+}
+
+/// 关键帧自动切线计算
+pub trait FrameValueTangent: Sized {
+    /// 计算关键帧的 (入切线, 出切线), 切线单位为 数值/时间
+    ///
+    /// * [mode] - 切线模式
+    /// * [prev] - 前一关键帧 (数值, 与当前关键帧的时间间隔)
+    /// * [value] - 当前关键帧数值
+    /// * [next] - 后一关键帧 (数值, 与当前关键帧的时间间隔)
+    ///
+    fn tangents(mode: ETangentMode, prev: Option<(&Self, KeyFrameCurveValue)>, value: &Self, next: Option<(&Self, KeyFrameCurveValue)>) -> (Self, Self);
+}
+
+/// 标量关键帧的切线计算, 返回 (入切线, 出切线)
+pub fn tangent_slopes(
+    mode: ETangentMode,
+    prev: Option<(KeyFrameCurveValue, KeyFrameCurveValue)>,
+    value: KeyFrameCurveValue,
+    next: Option<(KeyFrameCurveValue, KeyFrameCurveValue)>,
+) -> (KeyFrameCurveValue, KeyFrameCurveValue) {
+    // 时间间隔为 0 的相邻帧无法计算斜率, 视为不存在
+    let prev = prev.filter(|(_, delta)| *delta > KeyFrameCurveValue::EPSILON);
+    let next = next.filter(|(_, delta)| *delta > KeyFrameCurveValue::EPSILON);
+
+    let slope_in = prev.map(|(prev, delta)| (value - prev) / delta);
+    let slope_out = next.map(|(next, delta)| (next - value) / delta);
+
+    match mode {
+        ETangentMode::Free | ETangentMode::Flat => (0., 0.),
+        ETangentMode::Linear => {
+            let slope_in = slope_in.or(slope_out).unwrap_or(0.);
+            let slope_out = slope_out.unwrap_or(slope_in);
+            (slope_in, slope_out)
+        },
+        ETangentMode::Auto => {
+            let slope = match (prev, next) {
+                (Some((prev, delta_in)), Some((next, delta_out))) => (next - prev) / (delta_in + delta_out),
+                _ => slope_in.or(slope_out).unwrap_or(0.),
+            };
+            (slope, slope)
+        },
+        ETangentMode::Clamped => {
+            let slope = match (prev, next, slope_in, slope_out) {
+                (Some((prev, delta_in)), Some((next, delta_out)), Some(slope_in), Some(slope_out)) => {
+                    if slope_in * slope_out <= 0. {
+                        // 极值点 - 水平切线
+                        0.
+                    } else {
+                        // Fritsch-Carlson 单调条件: |切线| <= 3 * min(|相邻斜率|)
+                        let slope = (next - prev) / (delta_in + delta_out);
+                        let limit = 3. * KeyFrameCurveValue::min(slope_in.abs(), slope_out.abs());
+                        KeyFrameCurveValue::clamp(slope, -limit, limit)
+                    }
+                },
+                _ => slope_in.or(slope_out).unwrap_or(0.),
+            };
+            (slope, slope)
+        },
+    }
+}
+
+macro_rules! impl_frame_value_tangent {
+    ($($ty:ty),*) => {
+        $(
+            impl FrameValueTangent for $ty {
+                fn tangents(mode: ETangentMode, prev: Option<(&Self, KeyFrameCurveValue)>, value: &Self, next: Option<(&Self, KeyFrameCurveValue)>) -> (Self, Self) {
+                    let (intangent, outtangent) = tangent_slopes(
+                        mode,
+                        prev.map(|(value, delta)| (*value as KeyFrameCurveValue, delta)),
+                        *value as KeyFrameCurveValue,
+                        next.map(|(value, delta)| (*value as KeyFrameCurveValue, delta)),
+                    );
+                    (intangent as Self, outtangent as Self)
+                }
+            }
+        )*
+    };
+}
+
+impl_frame_value_tangent!(f32, f64);
@@ -0,0 +1,123 @@
+use std::f64::consts::TAU;
+
+use crate::curve::{frame::{FrameDataValue, FrameValueDistance, KeyFrameCurveValue}, tangent::{ETangentMode, FrameValueTangent, tangent_slopes}};
+
+/// 角度 (度)
+/// 插值沿最短弧进行, 350° -> 10° 旋转 20° 而不是 340°
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Degrees(pub KeyFrameCurveValue);
+
+/// 角度 (弧度)
+/// 插值沿最短弧进行
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Radians(pub KeyFrameCurveValue);
+
+impl Degrees {
+    /// 一周
+    pub const PERIOD: KeyFrameCurveValue = 360.;
+
+    pub fn to_radians(&self) -> Radians {
+        Radians(self.0.to_radians())
+    }
+}
+
+impl Radians {
+    /// 一周
+    pub const PERIOD: KeyFrameCurveValue = TAU as KeyFrameCurveValue;
+
+    pub fn to_degrees(&self) -> Degrees {
+        Degrees(self.0.to_degrees())
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(value: Radians) -> Self {
+        value.to_degrees()
+    }
+}
+
+impl From<Degrees> for Radians {
+    fn from(value: Degrees) -> Self {
+        value.to_radians()
+    }
+}
+
+/// from 到 to 的最短弧差值, 范围 [-period / 2, period / 2)
+pub fn shortest_angle_delta(from: KeyFrameCurveValue, to: KeyFrameCurveValue, period: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    let half = period * 0.5;
+    (to - from + half).rem_euclid(period) - half
+}
+
+/// 展开角度序列, 使相邻数值之差不超过半周, 之后普通的线性插值即走最短弧
+///
+/// * [values] - 按关键帧顺序排列的角度
+/// * [period] - 一周 (360 或 TAU)
+///
+pub fn unwrap_angles(values: &mut [KeyFrameCurveValue], period: KeyFrameCurveValue) {
+    for i in 1..values.len() {
+        values[i] = values[i - 1] + shortest_angle_delta(values[i - 1], values[i], period);
+    }
+}
+
+macro_rules! impl_angle_frame_value {
+    ($($ty:ident),*) => {
+        $(
+            impl $ty {
+                /// 归一化到 [0, PERIOD)
+                pub fn wrapped(&self) -> Self {
+                    Self(self.0.rem_euclid(Self::PERIOD))
+                }
+                /// 到 rhs 的最短弧差值
+                pub fn shortest_delta(&self, rhs: &Self) -> KeyFrameCurveValue {
+                    shortest_angle_delta(self.0, rhs.0, Self::PERIOD)
+                }
+                /// 展开关键帧序列, 使相邻关键帧之差不超过半周
+                pub fn unwrap(values: &mut [Self]) {
+                    for i in 1..values.len() {
+                        values[i] = Self(values[i - 1].0 + values[i - 1].shortest_delta(&values[i]));
+                    }
+                }
+            }
+
+            impl FrameDataValue for $ty {
+                fn interpolate(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+                    Self(self.0 + self.shortest_delta(rhs) * amount)
+                }
+                /// 叠加偏移量或切线, 不做环绕
+                fn append(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+                    Self(self.0 + rhs.0 * amount)
+                }
+                /// value2 先展开到与 value1 相差不超过半周, 切线不做环绕
+                fn hermite(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self {
+                    let value2 = value1.0 + value1.shortest_delta(value2);
+                    Self(KeyFrameCurveValue::hermite(&value1.0, &tangent1.0, &value2, &tangent2.0, amount, frame_delta))
+                }
+                fn size() -> usize {
+                    std::mem::size_of::<Self>()
+                }
+            }
+
+            impl FrameValueDistance for $ty {
+                /// 最短弧长
+                fn distance(&self, rhs: &Self) -> KeyFrameCurveValue {
+                    self.shortest_delta(rhs).abs()
+                }
+            }
+
+            impl FrameValueTangent for $ty {
+                /// 前后关键帧先展开到当前关键帧附近再计算斜率
+                fn tangents(mode: ETangentMode, prev: Option<(&Self, KeyFrameCurveValue)>, value: &Self, next: Option<(&Self, KeyFrameCurveValue)>) -> (Self, Self) {
+                    let (intangent, outtangent) = tangent_slopes(
+                        mode,
+                        prev.map(|(prev, delta)| (value.0 - prev.shortest_delta(value), delta)),
+                        value.0,
+                        next.map(|(next, delta)| (value.0 + value.shortest_delta(next), delta)),
+                    );
+                    (Self(intangent), Self(outtangent))
+                }
+            }
+        )*
+    };
+}
+
+impl_angle_frame_value!(Degrees, Radians);
@@ -0,0 +1,80 @@
+use crate::curve::{frame::{FrameDataValue, FrameValueDistance, KeyFrameCurveValue}, tangent::{ETangentMode, FrameValueTangent}};
+
+// 数组与元组无法实现外部的 Add, 因此逐分量直接实现 FrameDataValue,
+// 分量可以是任意 FrameDataValue (如 [f32; 3], (f32, Quaternion))
+
+/// 定长数组 (2D/3D/4D 位置, 缩放, UV 等), 逐分量插值
+impl<T: FrameDataValue, const N: usize> FrameDataValue for [T; N] {
+    fn interpolate(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        std::array::from_fn(|i| self[i].interpolate(&rhs[i], amount))
+    }
+    fn append(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        std::array::from_fn(|i| self[i].append(&rhs[i], amount))
+    }
+    fn hermite(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self {
+        std::array::from_fn(|i| T::hermite(&value1[i], &tangent1[i], &value2[i], &tangent2[i], amount, frame_delta))
+    }
+    fn size() -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+
+impl<T: FrameValueDistance, const N: usize> FrameValueDistance for [T; N] {
+    /// 各分量距离的欧氏范数
+    fn distance(&self, rhs: &Self) -> KeyFrameCurveValue {
+        self.iter().zip(rhs.iter()).map(|(a, b)| a.distance(b).powi(2)).sum::<KeyFrameCurveValue>().sqrt()
+    }
+}
+
+impl<T: FrameValueTangent + Clone, const N: usize> FrameValueTangent for [T; N] {
+    fn tangents(mode: ETangentMode, prev: Option<(&Self, KeyFrameCurveValue)>, value: &Self, next: Option<(&Self, KeyFrameCurveValue)>) -> (Self, Self) {
+        let tangents: [(T, T); N] = std::array::from_fn(|i| {
+            T::tangents(mode, prev.map(|(prev, delta)| (&prev[i], delta)), &value[i], next.map(|(next, delta)| (&next[i], delta)))
+        });
+        (std::array::from_fn(|i| tangents[i].0.clone()), std::array::from_fn(|i| tangents[i].1.clone()))
+    }
+}
+
+macro_rules! impl_tuple_frame_value {
+    ($(($($name:ident $index:tt),+)),*) => {
+        $(
+            /// 元组, 逐分量插值
+            impl<$($name: FrameDataValue),+> FrameDataValue for ($($name,)+) {
+                fn interpolate(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+                    ($(self.$index.interpolate(&rhs.$index, amount),)+)
+                }
+                fn append(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+                    ($(self.$index.append(&rhs.$index, amount),)+)
+                }
+                fn hermite(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self {
+                    ($($name::hermite(&value1.$index, &tangent1.$index, &value2.$index, &tangent2.$index, amount, frame_delta),)+)
+                }
+                fn size() -> usize {
+                    std::mem::size_of::<Self>()
+                }
+            }
+
+            impl<$($name: FrameValueDistance),+> FrameValueDistance for ($($name,)+) {
+                /// 各分量距离的欧氏范数
+                fn distance(&self, rhs: &Self) -> KeyFrameCurveValue {
+                    (0. $(+ self.$index.distance(&rhs.$index).powi(2))+).sqrt()
+                }
+            }
+
+            impl<$($name: FrameValueTangent),+> FrameValueTangent for ($($name,)+) {
+                fn tangents(mode: ETangentMode, prev: Option<(&Self, KeyFrameCurveValue)>, value: &Self, next: Option<(&Self, KeyFrameCurveValue)>) -> (Self, Self) {
+                    let tangents = ($(
+                        $name::tangents(mode, prev.map(|(prev, delta)| (&prev.$index, delta)), &value.$index, next.map(|(next, delta)| (&next.$index, delta))),
+                    )+);
+                    (($(tangents.$index.0,)+), ($(tangents.$index.1,)+))
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple_frame_value!(
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3)
+);
@@ -0,0 +1,328 @@
+use crate::curve::{frame::{FrameDataValue, FrameValueAlpha, FrameValueDistance, KeyFrameCurveValue}, tangent::{ETangentMode, FrameValueTangent}};
+
+/// 颜色插值空间
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EColorSpace {
+    /// 线性 RGB 空间, 物理上正确的混合
+    LinearRgb,
+    /// sRGB 空间, 与 CSS 默认的颜色插值一致
+    Srgb,
+    /// HSV 空间, 色相沿最短路径插值
+    Hsv,
+    /// OKLab 空间, 感知均匀, 渐变不发灰
+    OkLab,
+}
+
+/// 线性编码的 RGBA 颜色, 通道范围 [0, 1]
+/// append / hermite 在线性通道上逐分量计算, interpolate 在 space 指定的空间中插值
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearRgba {
+    pub r: KeyFrameCurveValue,
+    pub g: KeyFrameCurveValue,
+    pub b: KeyFrameCurveValue,
+    pub a: KeyFrameCurveValue,
+    /// 插值空间, 以插值起点的设置为准
+    pub space: EColorSpace,
+}
+
+/// sRGB 编码的 RGBA 颜色, 通道范围 [0, 1], alpha 为线性
+/// append / hermite 在 sRGB 通道上逐分量计算, interpolate 在 space 指定的空间中插值
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SrgbRgba {
+    pub r: KeyFrameCurveValue,
+    pub g: KeyFrameCurveValue,
+    pub b: KeyFrameCurveValue,
+    pub a: KeyFrameCurveValue,
+    /// 插值空间, 以插值起点的设置为准
+    pub space: EColorSpace,
+}
+
+impl Default for LinearRgba {
+    fn default() -> Self {
+        Self::new(0., 0., 0., 1.)
+    }
+}
+
+impl Default for SrgbRgba {
+    fn default() -> Self {
+        Self::new(0., 0., 0., 1.)
+    }
+}
+
+impl LinearRgba {
+    pub fn new(r: KeyFrameCurveValue, g: KeyFrameCurveValue, b: KeyFrameCurveValue, a: KeyFrameCurveValue) -> Self {
+        Self { r, g, b, a, space: EColorSpace::LinearRgb }
+    }
+    /// 指定插值空间
+    pub fn with_space(mut self, space: EColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+    pub fn to_srgb(&self) -> SrgbRgba {
+        SrgbRgba::from_linear_channels(self.channels(), self.space)
+    }
+    fn channels(&self) -> [KeyFrameCurveValue; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+    fn from_linear_channels(channels: [KeyFrameCurveValue; 4], space: EColorSpace) -> Self {
+        Self { r: channels[0], g: channels[1], b: channels[2], a: channels[3], space }
+    }
+    fn linear_channels(&self) -> [KeyFrameCurveValue; 4] {
+        self.channels()
+    }
+}
+
+impl SrgbRgba {
+    pub fn new(r: KeyFrameCurveValue, g: KeyFrameCurveValue, b: KeyFrameCurveValue, a: KeyFrameCurveValue) -> Self {
+        Self { r, g, b, a, space: EColorSpace::Srgb }
+    }
+    /// 由 8 位通道创建
+    pub fn from_u8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        let scale = 1. / 255.;
+        Self::new(r as KeyFrameCurveValue * scale, g as KeyFrameCurveValue * scale, b as KeyFrameCurveValue * scale, a as KeyFrameCurveValue * scale)
+    }
+    /// 转换为 8 位通道, 四舍五入
+    pub fn to_u8(&self) -> [u8; 4] {
+        let convert = |value: KeyFrameCurveValue| (KeyFrameCurveValue::clamp(value, 0., 1.) * 255.).round() as u8;
+        [convert(self.r), convert(self.g), convert(self.b), convert(self.a)]
+    }
+    /// 指定插值空间
+    pub fn with_space(mut self, space: EColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+    pub fn to_linear(&self) -> LinearRgba {
+        LinearRgba::from_linear_channels(self.linear_channels(), self.space)
+    }
+    fn channels(&self) -> [KeyFrameCurveValue; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+    fn from_linear_channels(channels: [KeyFrameCurveValue; 4], space: EColorSpace) -> Self {
+        Self { r: linear_to_srgb(channels[0]), g: linear_to_srgb(channels[1]), b: linear_to_srgb(channels[2]), a: channels[3], space }
+    }
+    fn linear_channels(&self) -> [KeyFrameCurveValue; 4] {
+        [srgb_to_linear(self.r), srgb_to_linear(self.g), srgb_to_linear(self.b), self.a]
+    }
+}
+
+impl From<SrgbRgba> for LinearRgba {
+    fn from(value: SrgbRgba) -> Self {
+        value.to_linear()
+    }
+}
+
+impl From<LinearRgba> for SrgbRgba {
+    fn from(value: LinearRgba) -> Self {
+        value.to_srgb()
+    }
+}
+
+macro_rules! impl_color_frame_value {
+    ($($ty:ty),*) => {
+        $(
+            impl FrameDataValue for $ty {
+                fn interpolate(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+                    let channels = interpolate_linear_channels(self.linear_channels(), rhs.linear_channels(), amount, self.space);
+                    Self::from_linear_channels(channels, self.space)
+                }
+                fn append(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+                    Self { r: self.r + rhs.r * amount, g: self.g + rhs.g * amount, b: self.b + rhs.b * amount, a: self.a + rhs.a * amount, space: self.space }
+                }
+                fn hermite(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self {
+                    let channel = |v1: KeyFrameCurveValue, t1: KeyFrameCurveValue, v2: KeyFrameCurveValue, t2: KeyFrameCurveValue| {
+                        KeyFrameCurveValue::hermite(&v1, &t1, &v2, &t2, amount, frame_delta)
+                    };
+                    Self {
+                        r: channel(value1.r, tangent1.r, value2.r, tangent2.r),
+                        g: channel(value1.g, tangent1.g, value2.g, tangent2.g),
+                        b: channel(value1.b, tangent1.b, value2.b, tangent2.b),
+                        a: channel(value1.a, tangent1.a, value2.a, tangent2.a),
+                        space: value1.space,
+                    }
+                }
+                fn size() -> usize {
+                    std::mem::size_of::<Self>()
+                }
+            }
+
+            impl FrameValueAlpha for $ty {
+                fn alpha(&self) -> KeyFrameCurveValue {
+                    self.a
+                }
+                fn with_alpha(&self, alpha: KeyFrameCurveValue) -> Self {
+                    Self { a: alpha, ..*self }
+                }
+                fn white() -> Self {
+                    Self::new(1., 1., 1., 1.)
+                }
+            }
+
+            impl FrameValueTangent for $ty {
+                /// 与 hermite 一致, 逐通道计算; 切线是通道的斜率, 不经过编码转换
+                fn tangents(mode: ETangentMode, prev: Option<(&Self, KeyFrameCurveValue)>, value: &Self, next: Option<(&Self, KeyFrameCurveValue)>) -> (Self, Self) {
+                    let prev = prev.map(|(prev, delta)| (prev.channels(), delta));
+                    let next = next.map(|(next, delta)| (next.channels(), delta));
+                    let (intangent, outtangent) = <[KeyFrameCurveValue; 4]>::tangents(
+                        mode,
+                        prev.as_ref().map(|(prev, delta)| (prev, *delta)),
+                        &value.channels(),
+                        next.as_ref().map(|(next, delta)| (next, *delta)),
+                    );
+                    let tangent = |t: [KeyFrameCurveValue; 4]| Self { r: t[0], g: t[1], b: t[2], a: t[3], space: value.space };
+                    (tangent(intangent), tangent(outtangent))
+                }
+            }
+
+            impl FrameValueDistance for $ty {
+                /// 通道的欧氏距离
+                fn distance(&self, rhs: &Self) -> KeyFrameCurveValue {
+                    let (a, b) = (self.channels(), rhs.channels());
+                    a.iter().zip(b.iter()).map(|(a, b)| (a - b) * (a - b)).sum::<KeyFrameCurveValue>().sqrt()
+                }
+            }
+        )*
+    };
+}
+
+impl_color_frame_value!(LinearRgba, SrgbRgba);
+
+/// 在指定空间中插值线性 RGBA 通道, alpha 始终线性插值
+fn interpolate_linear_channels(from: [KeyFrameCurveValue; 4], to: [KeyFrameCurveValue; 4], amount: KeyFrameCurveValue, space: EColorSpace) -> [KeyFrameCurveValue; 4] {
+    let lerp = |a: KeyFrameCurveValue, b: KeyFrameCurveValue| a + (b - a) * amount;
+    let alpha = lerp(from[3], to[3]);
+
+    let [r, g, b] = match space {
+        EColorSpace::LinearRgb => {
+            [lerp(from[0], to[0]), lerp(from[1], to[1]), lerp(from[2], to[2])]
+        },
+        EColorSpace::Srgb => {
+            let from = [linear_to_srgb(from[0]), linear_to_srgb(from[1]), linear_to_srgb(from[2])];
+            let to = [linear_to_srgb(to[0]), linear_to_srgb(to[1]), linear_to_srgb(to[2])];
+            [srgb_to_linear(lerp(from[0], to[0])), srgb_to_linear(lerp(from[1], to[1])), srgb_to_linear(lerp(from[2], to[2]))]
+        },
+        EColorSpace::Hsv => {
+            let mut from = srgb_to_hsv([linear_to_srgb(from[0]), linear_to_srgb(from[1]), linear_to_srgb(from[2])]);
+            let mut to = srgb_to_hsv([linear_to_srgb(to[0]), linear_to_srgb(to[1]), linear_to_srgb(to[2])]);
+
+            // 无彩色的色相无意义, 使用另一端的色相
+            if from[1] <= KeyFrameCurveValue::EPSILON {
+                from[0] = to[0];
+            }
+            if to[1] <= KeyFrameCurveValue::EPSILON {
+                to[0] = from[0];
+            }
+
+            let mut delta = to[0] - from[0];
+            if delta > 0.5 {
+                delta -= 1.;
+            } else if delta < -0.5 {
+                delta += 1.;
+            }
+            let hue = (from[0] + delta * amount).rem_euclid(1.);
+
+            let rgb = hsv_to_srgb([hue, lerp(from[1], to[1]), lerp(from[2], to[2])]);
+            [srgb_to_linear(rgb[0]), srgb_to_linear(rgb[1]), srgb_to_linear(rgb[2])]
+        },
+        EColorSpace::OkLab => {
+            let from = linear_to_oklab([from[0], from[1], from[2]]);
+            let to = linear_to_oklab([to[0], to[1], to[2]]);
+            oklab_to_linear([lerp(from[0], to[0]), lerp(from[1], to[1]), lerp(from[2], to[2])])
+        },
+    };
+
+    [r, g, b, alpha]
+}
+
+/// sRGB 通道转线性, 负值按对称处理
+pub fn srgb_to_linear(value: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    let abs = value.abs();
+    let result = if abs <= 0.04045 {
+        abs / 12.92
+    } else {
+        ((abs + 0.055) / 1.055).powf(2.4)
+    };
+    result.copysign(value)
+}
+
+/// 线性通道转 sRGB, 负值按对称处理
+pub fn linear_to_srgb(value: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    let abs = value.abs();
+    let result = if abs <= 0.0031308 {
+        abs * 12.92
+    } else {
+        1.055 * abs.powf(1. / 2.4) - 0.055
+    };
+    result.copysign(value)
+}
+
+/// sRGB 转 HSV, 色相范围 [0, 1)
+fn srgb_to_hsv(rgb: [KeyFrameCurveValue; 3]) -> [KeyFrameCurveValue; 3] {
+    let [r, g, b] = rgb;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta <= KeyFrameCurveValue::EPSILON {
+        0.
+    } else if max == r {
+        ((g - b) / delta / 6.).rem_euclid(1.)
+    } else if max == g {
+        ((b - r) / delta + 2.) / 6.
+    } else {
+        ((r - g) / delta + 4.) / 6.
+    };
+    let saturation = if max <= KeyFrameCurveValue::EPSILON { 0. } else { delta / max };
+
+    [hue, saturation, max]
+}
+
+fn hsv_to_srgb(hsv: [KeyFrameCurveValue; 3]) -> [KeyFrameCurveValue; 3] {
+    let [hue, saturation, value] = hsv;
+    let chroma = value * saturation;
+    let sector = hue * 6.;
+    let x = chroma * (1. - ((sector.rem_euclid(2.)) - 1.).abs());
+    let m = value - chroma;
+
+    let [r, g, b] = match sector.floor() as i32 {
+        0 => [chroma, x, 0.],
+        1 => [x, chroma, 0.],
+        2 => [0., chroma, x],
+        3 => [0., x, chroma],
+        4 => [x, 0., chroma],
+        _ => [chroma, 0., x],
+    };
+
+    [r + m, g + m, b + m]
+}
+
+/// 线性 RGB 转 OKLab (https://bottosson.github.io/posts/oklab/)
+/// 在 f64 中计算, 启用 amount_f64 时转换为同类型
+#[allow(clippy::unnecessary_cast)]
+fn linear_to_oklab(rgb: [KeyFrameCurveValue; 3]) -> [KeyFrameCurveValue; 3] {
+    let [r, g, b] = rgb.map(|value| value as f64);
+
+    let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+    let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+    let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+
+    [
+        0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+        1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+        0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+    ].map(|value| value as KeyFrameCurveValue)
+}
+
+#[allow(clippy::unnecessary_cast)]
+fn oklab_to_linear(lab: [KeyFrameCurveValue; 3]) -> [KeyFrameCurveValue; 3] {
+    let [lightness, a, b] = lab.map(|value| value as f64);
+
+    let l = (lightness + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+    let m = (lightness - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+    let s = (lightness - 0.089_484_177_5 * a - 1.291_485_548_0 * b).powi(3);
+
+    [
+        4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s,
+        -1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s,
+        -0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s,
+    ].map(|value| value as KeyFrameCurveValue)
+}
@@ -0,0 +1,15 @@
+//!
+//! 特殊插值规则的帧数据类型
+
+mod quaternion;
+mod color;
+mod angle;
+mod array;
+mod rounded;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_values;
+
+pub use quaternion::*;
+pub use color::*;
+pub use angle::*;
+pub use rounded::*;
@@ -0,0 +1,113 @@
+use nalgebra::{Point3, Quaternion, UnitQuaternion, Vector2, Vector3, Vector4};
+
+use crate::curve::{frame::{FrameDataValue, FrameValueDistance, FrameValueScale, KeyFrameCurveValue}, tangent::{ETangentMode, FrameValueTangent}};
+
+// nalgebra 的向量实现了 Add, 实现 FrameValueScale 即可获得 FrameDataValue
+macro_rules! impl_nalgebra_vector {
+    ($($ty:ident),*) => {
+        $(
+            impl FrameValueScale for $ty<KeyFrameCurveValue> {
+                fn scale(&self, rhs: KeyFrameCurveValue) -> Self {
+                    self * rhs
+                }
+            }
+
+            impl FrameValueDistance for $ty<KeyFrameCurveValue> {
+                fn distance(&self, rhs: &Self) -> KeyFrameCurveValue {
+                    (self - rhs).norm()
+                }
+            }
+
+            impl FrameValueTangent for $ty<KeyFrameCurveValue> {
+                fn tangents(mode: ETangentMode, prev: Option<(&Self, KeyFrameCurveValue)>, value: &Self, next: Option<(&Self, KeyFrameCurveValue)>) -> (Self, Self) {
+                    let (intangent, outtangent) = vector_tangents(
+                        mode,
+                        prev.map(|(prev, delta)| (prev.as_slice(), delta)),
+                        value.as_slice(),
+                        next.map(|(next, delta)| (next.as_slice(), delta)),
+                    );
+                    ($ty::from_vec(intangent), $ty::from_vec(outtangent))
+                }
+            }
+        )*
+    };
+}
+
+/// 逐分量计算切线
+fn vector_tangents(
+    mode: ETangentMode,
+    prev: Option<(&[KeyFrameCurveValue], KeyFrameCurveValue)>,
+    value: &[KeyFrameCurveValue],
+    next: Option<(&[KeyFrameCurveValue], KeyFrameCurveValue)>,
+) -> (Vec<KeyFrameCurveValue>, Vec<KeyFrameCurveValue>) {
+    value.iter().enumerate().map(|(i, value)| {
+        KeyFrameCurveValue::tangents(mode, prev.map(|(prev, delta)| (&prev[i], delta)), value, next.map(|(next, delta)| (&next[i], delta)))
+    }).unzip()
+}
+
+impl_nalgebra_vector!(Vector2, Vector3, Vector4);
+
+/// 点不能与点相加, 按坐标向量插值
+impl FrameDataValue for Point3<KeyFrameCurveValue> {
+    fn interpolate(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        Point3::from(self.coords.interpolate(&rhs.coords, amount))
+    }
+    /// 叠加偏移量或切线
+    fn append(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        self + rhs.coords * amount
+    }
+    fn hermite(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self {
+        Point3::from(Vector3::hermite(&value1.coords, &tangent1.coords, &value2.coords, &tangent2.coords, amount, frame_delta))
+    }
+    fn size() -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+
+impl FrameValueDistance for Point3<KeyFrameCurveValue> {
+    fn distance(&self, rhs: &Self) -> KeyFrameCurveValue {
+        nalgebra::distance(self, rhs)
+    }
+}
+
+impl FrameValueTangent for Point3<KeyFrameCurveValue> {
+    fn tangents(mode: ETangentMode, prev: Option<(&Self, KeyFrameCurveValue)>, value: &Self, next: Option<(&Self, KeyFrameCurveValue)>) -> (Self, Self) {
+        let (intangent, outtangent) = Vector3::tangents(
+            mode,
+            prev.map(|(prev, delta)| (&prev.coords, delta)),
+            &value.coords,
+            next.map(|(next, delta)| (&next.coords, delta)),
+        );
+        (Point3::from(intangent), Point3::from(outtangent))
+    }
+}
+
+/// 插值使用最短路径的 slerp, 两旋转夹角接近 0 时退化为 nlerp
+///
+/// 切线不是单位四元数, 因此不实现 FrameValueTangent, 自动切线模式请使用 Quaternion
+impl FrameDataValue for UnitQuaternion<KeyFrameCurveValue> {
+    fn interpolate(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        let rhs = if self.coords.dot(&rhs.coords) < 0. { UnitQuaternion::new_unchecked(-rhs.into_inner()) } else { *rhs };
+        self.try_slerp(&rhs, amount, KeyFrameCurveValue::EPSILON)
+            .unwrap_or_else(|| self.nlerp(&rhs, amount))
+    }
+    /// 在 self 的基础上叠加 rhs 旋转的 amount 部分
+    fn append(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        self * UnitQuaternion::identity().interpolate(rhs, amount)
+    }
+    /// 逐分量 hermite 插值后归一化 (GLTF CUBICSPLINE rotation)
+    fn hermite(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self {
+        let coords = Vector4::hermite(&value1.coords, &tangent1.coords, &value2.coords, &tangent2.coords, amount, frame_delta);
+        UnitQuaternion::from_quaternion(Quaternion::from(coords))
+    }
+    fn size() -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+
+impl FrameValueDistance for UnitQuaternion<KeyFrameCurveValue> {
+    /// 两个旋转之间的夹角 (弧度)
+    fn distance(&self, rhs: &Self) -> KeyFrameCurveValue {
+        self.angle_to(rhs)
+    }
+}
@@ -0,0 +1,153 @@
+use std::ops::Mul;
+
+use crate::curve::{frame::{FrameDataValue, FrameValueDistance, KeyFrameCurveValue}, tangent::{ETangentMode, FrameValueTangent}};
+
+/// 四元数旋转 (x, y, z, w)
+/// 插值使用最短路径的 slerp, hermite 插值后重新归一化, 适用于 GLTF rotation 通道
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub x: KeyFrameCurveValue,
+    pub y: KeyFrameCurveValue,
+    pub z: KeyFrameCurveValue,
+    pub w: KeyFrameCurveValue,
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Quaternion {
+    /// 夹角小于该阈值 (cos 值大于) 时 slerp 退化为 nlerp
+    const SLERP_THRESHOLD: KeyFrameCurveValue = 0.9995;
+
+    pub fn new(x: KeyFrameCurveValue, y: KeyFrameCurveValue, z: KeyFrameCurveValue, w: KeyFrameCurveValue) -> Self {
+        Self { x, y, z, w }
+    }
+    pub fn identity() -> Self {
+        Self::new(0., 0., 0., 1.)
+    }
+    /// 绕归一化的轴旋转 angle 弧度
+    pub fn from_axis_angle(axis: [KeyFrameCurveValue; 3], angle: KeyFrameCurveValue) -> Self {
+        let half = angle * 0.5;
+        let sin = half.sin();
+        Self::new(axis[0] * sin, axis[1] * sin, axis[2] * sin, half.cos())
+    }
+    pub fn dot(&self, rhs: &Self) -> KeyFrameCurveValue {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+    pub fn length(&self) -> KeyFrameCurveValue {
+        self.dot(self).sqrt()
+    }
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length <= KeyFrameCurveValue::EPSILON {
+            Self::identity()
+        } else {
+            self.weighted(1. / length)
+        }
+    }
+    /// 归一化线性插值, 走最短路径
+    pub fn nlerp(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        let rhs = if self.dot(rhs) < 0. { rhs.weighted(-1.) } else { *rhs };
+        self.weighted(1. - amount).sum(&rhs.weighted(amount)).normalize()
+    }
+    /// 球面线性插值, 走最短路径
+    pub fn slerp(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        let mut cos = self.dot(rhs);
+        let rhs = if cos < 0. {
+            cos = -cos;
+            rhs.weighted(-1.)
+        } else {
+            *rhs
+        };
+
+        if cos > Self::SLERP_THRESHOLD {
+            return self.nlerp(&rhs, amount);
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        let scale1 = ((1. - amount) * theta).sin() / sin;
+        let scale2 = (amount * theta).sin() / sin;
+
+        self.weighted(scale1).sum(&rhs.weighted(scale2)).normalize()
+    }
+
+    fn weighted(&self, rhs: KeyFrameCurveValue) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+    fn sum(&self, rhs: &Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z, self.w + rhs.w)
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl FrameDataValue for Quaternion {
+    fn interpolate(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        self.slerp(rhs, amount)
+    }
+    /// 在 self 的基础上叠加 rhs 旋转的 amount 部分
+    fn append(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        (*self * Self::identity().slerp(rhs, amount)).normalize()
+    }
+    /// 逐分量 hermite 插值后归一化 (GLTF CUBICSPLINE rotation)
+    fn hermite(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self {
+        let squared = amount * amount;
+        let cubed = amount * squared;
+        let part1 = ((2. * cubed) - (3. * squared)) + 1.;
+        let part2 = (-2. * cubed) + (3. * squared);
+        let part3 = (cubed - (2. * squared)) + amount;
+        let part4 = cubed - squared;
+
+        value1.weighted(part1)
+            .sum(&value2.weighted(part2))
+            .sum(&tangent1.weighted(part3 * frame_delta))
+            .sum(&tangent2.weighted(part4 * frame_delta))
+            .normalize()
+    }
+    fn size() -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+
+impl FrameValueDistance for Quaternion {
+    /// 两个旋转之间的夹角 (弧度)
+    fn distance(&self, rhs: &Self) -> KeyFrameCurveValue {
+        2. * KeyFrameCurveValue::clamp(self.dot(rhs).abs(), 0., 1.).acos()
+    }
+}
+
+impl FrameValueTangent for Quaternion {
+    /// 相邻关键帧先翻转到与当前关键帧同一半球, 再逐分量计算
+    fn tangents(mode: ETangentMode, prev: Option<(&Self, KeyFrameCurveValue)>, value: &Self, next: Option<(&Self, KeyFrameCurveValue)>) -> (Self, Self) {
+        let components = |rhs: &Self| {
+            let rhs = if value.dot(rhs) < 0. { rhs.weighted(-1.) } else { *rhs };
+            [rhs.x, rhs.y, rhs.z, rhs.w]
+        };
+        let prev = prev.map(|(prev, delta)| (components(prev), delta));
+        let next = next.map(|(next, delta)| (components(next), delta));
+        let (intangent, outtangent) = <[KeyFrameCurveValue; 4]>::tangents(
+            mode,
+            prev.as_ref().map(|(prev, delta)| (prev, *delta)),
+            &components(value),
+            next.as_ref().map(|(next, delta)| (next, *delta)),
+        );
+        (
+            Self::new(intangent[0], intangent[1], intangent[2], intangent[3]),
+            Self::new(outtangent[0], outtangent[1], outtangent[2], outtangent[3]),
+        )
+    }
+}
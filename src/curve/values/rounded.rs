@@ -0,0 +1,79 @@
+use std::marker::PhantomData;
+
+use crate::curve::frame::{FrameDataValue, FrameValueDistance, FrameValueInteger, FrameValueLimit, KeyFrameCurveValue};
+
+/// 整数取整方式, 在 f64 中取整
+pub trait FrameValueRounding {
+    fn round(value: f64) -> f64;
+}
+
+/// 向下取整
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RoundFloor;
+
+/// 四舍五入 (整数类型的默认方式)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RoundNearest;
+
+/// 向上取整
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RoundCeil;
+
+impl FrameValueRounding for RoundFloor {
+    fn round(value: f64) -> f64 {
+        value.floor()
+    }
+}
+
+impl FrameValueRounding for RoundNearest {
+    fn round(value: f64) -> f64 {
+        value.round()
+    }
+}
+
+impl FrameValueRounding for RoundCeil {
+    fn round(value: f64) -> f64 {
+        value.ceil()
+    }
+}
+
+/// 指定取整方式的整数帧数据, 如 Rounded<u8, RoundFloor>
+/// 在浮点数中计算后按 P 取整一次, 超出范围时饱和
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rounded<T, P> {
+    pub value: T,
+    policy: PhantomData<P>,
+}
+
+impl<T: FrameValueInteger, P: FrameValueRounding> Rounded<T, P> {
+    pub fn new(value: T) -> Self {
+        Self { value, policy: PhantomData }
+    }
+}
+
+impl<T: FrameValueInteger, P: FrameValueRounding + Clone> FrameDataValue for Rounded<T, P> {
+    fn interpolate(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        Self::new(self.value.interpolate_rounded::<P>(&rhs.value, amount))
+    }
+    fn append(&self, rhs: &Self, amount: KeyFrameCurveValue) -> Self {
+        Self::new(self.value.append_rounded::<P>(&rhs.value, amount))
+    }
+    fn hermite(value1: &Self, tangent1: &Self, value2: &Self, tangent2: &Self, amount: KeyFrameCurveValue, frame_delta: KeyFrameCurveValue) -> Self {
+        Self::new(T::hermite_rounded::<P>(&value1.value, &tangent1.value, &value2.value, &tangent2.value, amount, frame_delta))
+    }
+    fn size() -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+
+impl<T: FrameValueInteger + FrameValueDistance, P> FrameValueDistance for Rounded<T, P> {
+    fn distance(&self, rhs: &Self) -> KeyFrameCurveValue {
+        self.value.distance(&rhs.value)
+    }
+}
+
+impl<T: FrameValueInteger + FrameValueLimit, P: FrameValueRounding> FrameValueLimit for Rounded<T, P> {
+    fn limit(&self, min: &Self, max: &Self) -> Self {
+        Self::new(self.value.limit(&min.value, &max.value))
+    }
+}
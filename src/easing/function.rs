@@ -87,7 +87,7 @@ pub fn back_in(x: KeyFrameCurveValue) -> KeyFrameCurveValue {
     let c1 = 1.70158;
     let c3 = c1 + 1.;
 
-    return c3 * x * xx - c1 * xx;
+    c3 * x * xx - c1 * xx
 }
 pub fn back_out(x: KeyFrameCurveValue) -> KeyFrameCurveValue {
     let c1 = 1.70158;
@@ -95,7 +95,7 @@ pub fn back_out(x: KeyFrameCurveValue) -> KeyFrameCurveValue {
 
     let temp = x - 1.;
 
-    return 1. + c3 * temp.powi(3) + c1 * temp.powi(2);
+    1. + c3 * temp.powi(3) + c1 * temp.powi(2)
 }
 pub fn back_in_out(x: KeyFrameCurveValue) -> KeyFrameCurveValue {
     let c1 = 1.70158;
@@ -121,19 +121,19 @@ pub fn bounce_out(mut x: KeyFrameCurveValue) -> KeyFrameCurveValue {
     let d1 = 2.75;
 
     if x < 1. / d1 {
-        return n1 * x * x;
+        n1 * x * x
     } else if x < 2.0 / d1 {
         x -= 1.5 / d1;
 
-        return n1 * x * x + 0.75;
+        n1 * x * x + 0.75
     } else if x < 2.5 / d1 {
         x -= 2.25 / d1;
 
-        return n1 * x * x + 0.9375;
+        n1 * x * x + 0.9375
     } else {
         x -= 2.625 / d1;
 
-        return n1 * x * x + 0.984375;
+        n1 * x * x + 0.984375
     }
 }
 pub fn bounce_in_out(x: KeyFrameCurveValue) -> KeyFrameCurveValue {
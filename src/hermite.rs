@@ -13,5 +13,5 @@ pub fn hermite(value1: KeyFrameCurveValue, tangent1: KeyFrameCurveValue, value2:
     let part3 = (cubed - (_2 * squared)) + amount;
     let part4 = cubed - squared;
 
-    return (((value1 * part1) + (value2 * part2)) + (tangent1 * part3)) + (tangent2 * part4);
+    (((value1 * part1) + (value2 * part2)) + (tangent1 * part3)) + (tangent2 * part4)
 }
\ No newline at end of file
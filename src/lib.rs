@@ -1,9 +1,11 @@
-#![feature(core_intrinsics)]
+#![allow(clippy::just_underscores_and_digits)]
 
 extern crate simba;
 
 mod hermite;
 mod bezier;
+mod spring;
+mod noise;
 mod easing;
 mod curve;
 mod steps;
@@ -12,6 +14,8 @@ mod types;
 
 pub use hermite::*;
 pub use bezier::*;
+pub use spring::*;
+pub use noise::*;
 pub use easing::*;
 pub use curve::*;
 pub use steps::*;
@@ -20,6 +24,8 @@ pub use amount::*;
 /// 进度计算参数
 /// 对于 Step 模式 第一个参数为 步进数目
 /// 对于 CubicBezier 模式 四个参数分别对应 (x1, y1, x2, y2)
+/// 对于 Spring 模式 四个参数分别对应 (无阻尼角频率, 阻尼比, 初始速度, 稳定时长), 稳定时长在创建时计算
+/// Linear 模式的停靠点存放在 AnimationAmountCalc 中
 #[derive(Debug, Clone, Copy)]
 pub struct AmountParam(pub KeyFrameCurveValue, pub KeyFrameCurveValue, pub KeyFrameCurveValue, pub KeyFrameCurveValue);
 
@@ -36,6 +42,8 @@ pub enum EAmountMode {
     Easing(EEasingMode),
     Steps(EStepMode),
     CubicBezier,
+    Spring,
+    Linear,
 }
 
 impl EAmountMode {
@@ -45,6 +53,9 @@ impl EAmountMode {
             EAmountMode::Easing(mode) => Self::get_calc_amount_easing(mode),
             EAmountMode::Steps(mode) => Self::get_calc_amount_steps(mode),
             EAmountMode::CubicBezier => Self::calc_amount_cubic_bezier,
+            EAmountMode::Spring => Self::calc_amount_spring,
+            // 停靠点由 AnimationAmountCalc::from_linear_stops 持有, 经其自身的计算入口求值, 不使用此函数
+            EAmountMode::Linear => Self::calc_amount_none,
         }
     }
     
@@ -105,37 +116,51 @@ impl EAmountMode {
         let y2 = param.3;
         bezier::cubic_bezier(x1, y1, x2, y2, amount)
     }
+
+    fn calc_amount_spring(amount: KeyFrameCurveValue, param: &AmountParam) -> KeyFrameCurveValue {
+        spring::spring_amount(param.0, param.1, param.2, param.3, amount)
+    }
+
+    /// * [stops] - 依次存放 所有停靠点的输入进度, 所有停靠点的输出进度
+    pub(crate) fn calc_amount_linear(amount: KeyFrameCurveValue, stops: &[KeyFrameCurveValue]) -> KeyFrameCurveValue {
+        let count = stops.len() / 2;
+        let (inputs, outputs) = stops.split_at(count);
+
+        // 最后一个输入不大于 amount 的停靠点, 超出首尾时沿首尾两段外插
+        let index = inputs.partition_point(|input| *input <= amount);
+        let index = usize::min(index.saturating_sub(1), count - 2);
+
+        let (input1, input2) = (inputs[index], inputs[index + 1]);
+        let (output1, output2) = (outputs[index], outputs[index + 1]);
+        if input1 == input2 {
+            output2
+        } else {
+            output1 + (amount - input1) / (input2 - input1) * (output2 - output1)
+        }
+    }
 }
 
 pub fn amount_step_start(x: KeyFrameCurveValue, param: &AmountParam) -> KeyFrameCurveValue {
     let t = 1.0 / param.0 as KeyFrameCurveValue;
     let ix = (x / t).floor();
-    let ix = (ix + 1.) * t;
-
-    ix
+    (ix + 1.) * t
 }
 pub fn amount_step_end(x: KeyFrameCurveValue, param: &AmountParam) -> KeyFrameCurveValue {
     let t = 1.0 / param.0 as KeyFrameCurveValue;
     let ix = (x / t).floor();
-    let ix = ix * t;
-
-    ix
+    ix * t
 }
 pub fn amount_step_none(x: KeyFrameCurveValue, param: &AmountParam) -> KeyFrameCurveValue {
     let t = 1.0 / param.0 as KeyFrameCurveValue;
     let ix = (x / t).floor();
     let t = 1.0 / (param.0 as KeyFrameCurveValue - 1.0);
-    let ix = ix * t;
-
-    ix
+    ix * t
 }
 pub fn amount_step_both(x: KeyFrameCurveValue, param: &AmountParam) -> KeyFrameCurveValue {
     let t = 1.0 / param.0 as KeyFrameCurveValue;
     let ix = (x / t).floor();
     let t = 1.0 / (param.0 as KeyFrameCurveValue + 1.0);
-    let ix = (ix + 1.0) * t;
-
-    ix
+    (ix + 1.0) * t
 }
 
 pub fn amount_back_in(x: KeyFrameCurveValue, _: &AmountParam) -> KeyFrameCurveValue {
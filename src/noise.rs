@@ -0,0 +1,65 @@
+//!  实现带种子的一维梯度噪声 (Perlin) 相关函数
+
+use crate::types::KeyFrameCurveValue;
+
+/// 噪声曲线参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseParam {
+    /// 随机种子, 相同种子产生相同的噪声
+    pub seed: u32,
+    /// 频率 (每秒周期数)
+    pub frequency: KeyFrameCurveValue,
+    /// 叠加层数, 每层频率翻倍、振幅减半
+    pub octaves: u32,
+    /// 振幅, 噪声输出范围为 [-amplitude, amplitude]
+    pub amplitude: KeyFrameCurveValue,
+}
+
+impl Default for NoiseParam {
+    fn default() -> Self {
+        Self { seed: 0, frequency: 1., octaves: 1, amplitude: 1. }
+    }
+}
+
+/// 整数格点的伪随机梯度, 范围 [-1, 1]
+fn noise_gradient(seed: u32, index: i32) -> KeyFrameCurveValue {
+    let mut hash = (index as u32) ^ seed.wrapping_mul(0x9E37_79B9);
+    hash = (hash ^ (hash >> 16)).wrapping_mul(0x7FEB_352D);
+    hash = (hash ^ (hash >> 15)).wrapping_mul(0x846C_A68B);
+    hash ^= hash >> 16;
+
+    (hash as KeyFrameCurveValue / u32::MAX as KeyFrameCurveValue) * 2. - 1.
+}
+
+/// 一维梯度噪声, 整数位置处为 0, 范围 [-1, 1]
+pub fn gradient_noise(seed: u32, x: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    let floor = x.floor();
+    let index = floor as i32;
+    let t = x - floor;
+
+    let value1 = noise_gradient(seed, index) * t;
+    let value2 = noise_gradient(seed, index.wrapping_add(1)) * (t - 1.);
+
+    // 6t^5 - 15t^4 + 10t^3
+    let fade = t * t * t * (t * (t * 6. - 15.) + 10.);
+
+    // 一维梯度噪声理论最大值为 0.5
+    (value1 + (value2 - value1) * fade) * 2.
+}
+
+/// 多层叠加的一维梯度噪声, 结果按总振幅归一化到 [-1, 1]
+pub fn fractal_noise(seed: u32, x: KeyFrameCurveValue, octaves: u32) -> KeyFrameCurveValue {
+    let mut result = 0.;
+    let mut total = 0.;
+    let mut frequency = 1.;
+    let mut amplitude = 1.;
+
+    for octave in 0..u32::max(octaves, 1) {
+        result += gradient_noise(seed.wrapping_add(octave), x * frequency) * amplitude;
+        total += amplitude;
+        frequency *= 2.;
+        amplitude *= 0.5;
+    }
+
+    result / total
+}
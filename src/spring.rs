@@ -0,0 +1,110 @@
+//!  实现弹簧 (阻尼谐振子) 进度相关函数
+
+use crate::types::KeyFrameCurveValue;
+
+/// 判定弹簧静止的位移阈值
+pub const SPRING_SETTLING_THRESHOLD: KeyFrameCurveValue = 0.001;
+/// 无阻尼时的稳定时长上限 (秒)
+pub const SPRING_MAX_DURATION: KeyFrameCurveValue = 60.;
+
+/// 弹簧的 (无阻尼角频率, 阻尼比)
+pub fn spring_omega_zeta(mass: KeyFrameCurveValue, stiffness: KeyFrameCurveValue, damping: KeyFrameCurveValue) -> (KeyFrameCurveValue, KeyFrameCurveValue) {
+    let omega0 = KeyFrameCurveValue::sqrt(stiffness / mass);
+    let zeta = damping / (2. * KeyFrameCurveValue::sqrt(stiffness * mass));
+    (omega0, zeta)
+}
+
+/// 弹簧 t 秒时相对目标的位移, 初始位移为 1
+///
+/// * [velocity] - 初始速度, 以 进度/秒 为单位, 正值指向目标
+pub fn spring_displacement(mass: KeyFrameCurveValue, stiffness: KeyFrameCurveValue, damping: KeyFrameCurveValue, velocity: KeyFrameCurveValue, t: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    let (omega0, zeta) = spring_omega_zeta(mass, stiffness, damping);
+    displacement(omega0, zeta, velocity, t)
+}
+
+fn displacement(omega0: KeyFrameCurveValue, zeta: KeyFrameCurveValue, velocity: KeyFrameCurveValue, t: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    if zeta < 1. {
+        // 欠阻尼
+        let omegad = omega0 * KeyFrameCurveValue::sqrt(1. - zeta * zeta);
+        let b = (zeta * omega0 - velocity) / omegad;
+        KeyFrameCurveValue::exp(-zeta * omega0 * t) * (KeyFrameCurveValue::cos(omegad * t) + b * KeyFrameCurveValue::sin(omegad * t))
+    } else if zeta == 1. {
+        // 临界阻尼
+        let b = omega0 - velocity;
+        KeyFrameCurveValue::exp(-omega0 * t) * (1. + b * t)
+    } else {
+        // 过阻尼
+        let root = KeyFrameCurveValue::sqrt(zeta * zeta - 1.);
+        let r1 = -omega0 * (zeta - root);
+        let r2 = -omega0 * (zeta + root);
+        let c2 = (-velocity - r1) / (r2 - r1);
+        let c1 = 1. - c2;
+        c1 * KeyFrameCurveValue::exp(r1 * t) + c2 * KeyFrameCurveValue::exp(r2 * t)
+    }
+}
+
+/// 弹簧稳定时长 (秒), 此后位移不再超出 SPRING_SETTLING_THRESHOLD
+pub fn spring_settling_duration(mass: KeyFrameCurveValue, stiffness: KeyFrameCurveValue, damping: KeyFrameCurveValue, velocity: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    let (omega0, zeta) = spring_omega_zeta(mass, stiffness, damping);
+    settling_duration(omega0, zeta, velocity)
+}
+
+fn settling_duration(omega0: KeyFrameCurveValue, zeta: KeyFrameCurveValue, velocity: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    if zeta * omega0 <= KeyFrameCurveValue::EPSILON {
+        return SPRING_MAX_DURATION;
+    }
+
+    if zeta < 1. {
+        // 欠阻尼 由振幅包络直接求解
+        let omegad = omega0 * KeyFrameCurveValue::sqrt(1. - zeta * zeta);
+        let b = (zeta * omega0 - velocity) / omegad;
+        let envelope = KeyFrameCurveValue::sqrt(1. + b * b);
+        let duration = KeyFrameCurveValue::ln(envelope / SPRING_SETTLING_THRESHOLD) / (zeta * omega0);
+        return KeyFrameCurveValue::clamp(duration, 0., SPRING_MAX_DURATION);
+    }
+
+    // 临界/过阻尼 包络单调递减后二分求解
+    let envelope = |t: KeyFrameCurveValue| -> KeyFrameCurveValue {
+        if zeta == 1. {
+            (1. + KeyFrameCurveValue::abs(omega0 - velocity) * t) * KeyFrameCurveValue::exp(-omega0 * t)
+        } else {
+            let root = KeyFrameCurveValue::sqrt(zeta * zeta - 1.);
+            let r1 = -omega0 * (zeta - root);
+            let r2 = -omega0 * (zeta + root);
+            let c2 = (-velocity - r1) / (r2 - r1);
+            let c1 = 1. - c2;
+            KeyFrameCurveValue::abs(c1) * KeyFrameCurveValue::exp(r1 * t) + KeyFrameCurveValue::abs(c2) * KeyFrameCurveValue::exp(r2 * t)
+        }
+    };
+
+    let mut upper = 1. / omega0;
+    while envelope(upper) > SPRING_SETTLING_THRESHOLD {
+        upper *= 2.;
+        if upper >= SPRING_MAX_DURATION {
+            return SPRING_MAX_DURATION;
+        }
+    }
+    let mut lower = 0.;
+    for _ in 0..32 {
+        let middle = (lower + upper) * 0.5;
+        if envelope(middle) > SPRING_SETTLING_THRESHOLD {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+
+    upper
+}
+
+/// 弹簧进度, amount 在 [0, 1] 内对应 [0, duration]
+///
+/// * [omega0] [zeta] - 无阻尼角频率与阻尼比, 由 spring_omega_zeta 获得
+/// * [velocity] - 初始速度, 以 进度/秒 为单位
+/// * [duration] - 稳定时长, 由 spring_settling_duration 获得
+pub fn spring_amount(omega0: KeyFrameCurveValue, zeta: KeyFrameCurveValue, velocity: KeyFrameCurveValue, duration: KeyFrameCurveValue, amount: KeyFrameCurveValue) -> KeyFrameCurveValue {
+    if amount >= 1. {
+        return 1.;
+    }
+    1. - displacement(omega0, zeta, velocity, amount * duration)
+}
@@ -1,4 +1,5 @@
 #![feature(test)]
+#![allow(unused_assignments, clippy::assign_op_pattern)]
 extern crate test;
 
 #[cfg(test)]
@@ -37,6 +38,959 @@ mod test_frame {
         // });
     }   
 
+    #[test]
+    fn test_catmull_rom() {
+        for mode in [ECatmullRomMode::Uniform, ECatmullRomMode::Centripetal, ECatmullRomMode::Chordal] {
+            let mut curve = FrameCurve::curve_catmull_rom(30, mode);
+            FrameCurve::curve_catmull_rom_frame(&mut curve, 0, 0.0f32);
+            FrameCurve::curve_catmull_rom_frame(&mut curve, 10, 1.0f32);
+            FrameCurve::curve_catmull_rom_frame(&mut curve, 20, 2.0f32);
+            FrameCurve::curve_catmull_rom_frame(&mut curve, 30, 3.0f32);
+
+            // 曲线经过关键帧, 等距共线关键帧之间退化为线性
+            assert!((curve.interple(10. / 30., &AnimationAmountCalc::default()) - 1.0).abs() < 0.0001);
+            assert!((curve.interple(15. / 30., &AnimationAmountCalc::default()) - 1.5).abs() < 0.0001);
+            assert!((curve.interple(25. / 30., &AnimationAmountCalc::default()) - 2.5).abs() < 0.0001);
+        }
+
+        let mut curve = FrameCurve::curve_catmull_rom(30, ECatmullRomMode::Centripetal);
+        FrameCurve::curve_catmull_rom_frame(&mut curve, 0, 0.0f32);
+        FrameCurve::curve_catmull_rom_frame(&mut curve, 10, 1.0f32);
+        FrameCurve::curve_catmull_rom_frame(&mut curve, 20, 0.0f32);
+        let v = curve.interple(5. / 30., &AnimationAmountCalc::default());
+        assert!(v > 0.5 && v < 1.0);
+    }
+
+    #[test]
+    fn test_tangent_mode() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        // Linear 切线 - 关键帧之间为直线
+        let mut curve = FrameCurve::curve_minmax_curve(0.0f32, 1.0f32, 30);
+        FrameCurve::curve_minmax_curve_frame_mode(&mut curve, 0, 0.0, ETangentMode::Linear);
+        FrameCurve::curve_minmax_curve_frame_mode(&mut curve, 10, 1.0, ETangentMode::Linear);
+        FrameCurve::curve_minmax_curve_frame_mode(&mut curve, 30, 0.0, ETangentMode::Linear);
+        assert!((curve.interple(5. / 30., &amountcalc) - 0.5).abs() < 0.0001);
+        assert!((curve.interple(20. / 30., &amountcalc) - 0.5).abs() < 0.0001);
+
+        // Clamped 切线 - 不超出相邻关键帧的范围
+        let mut curve = FrameCurve::curve_minmax_curve(0.0f32, 1.0f32, 30);
+        FrameCurve::curve_minmax_curve_frame_mode(&mut curve, 0, 0.0, ETangentMode::Clamped);
+        FrameCurve::curve_minmax_curve_frame_mode(&mut curve, 2, 1.0, ETangentMode::Clamped);
+        FrameCurve::curve_minmax_curve_frame_mode(&mut curve, 30, 1.1, ETangentMode::Clamped);
+        for i in 0..=30 {
+            let v = curve.interple(i as KeyFrameCurveValue / 30., &amountcalc);
+            assert!((0.0..=1.1001).contains(&v));
+        }
+
+        // 移动关键帧后重新计算 Auto 切线
+        let mut curve = FrameCurve::curve_cubic_spline(30);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 0, 0.0f32, ETangentMode::Auto);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 30, 2.0f32, ETangentMode::Auto);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 60, 2.0f32, ETangentMode::Auto);
+        assert!((curve.cubic_spline_values[1].intangent() - 1.0).abs() < 0.0001);
+        let index = FrameCurve::curve_cubic_splice_move_frame(&mut curve, 2, 60, 4.0f32);
+        assert_eq!(index, 2);
+        assert!((curve.cubic_spline_values[1].outtangent() - 2.0).abs() < 0.0001);
+        assert!((curve.interple(0.5, &amountcalc) - 1.0).abs() < 0.0001);
+
+        // 在 Auto 关键帧旁插入自由切线关键帧, 相邻关键帧的切线同样重新计算
+        FrameCurve::curve_cubic_splice_frame(&mut curve, 90, 0.0f32, 0.0f32, 0.0f32);
+        assert!((curve.cubic_spline_values[2].intangent() + 1.0).abs() < 0.0001);
+        assert_eq!(*curve.cubic_spline_values[3].intangent(), 0.0);
+
+        // 切线模式只作用于各自的关键帧, 自由切线的关键帧保持原切线
+        let mut curve = FrameCurve::curve_cubic_spline(30);
+        FrameCurve::curve_cubic_splice_frame(&mut curve, 0, 0.0f32, 5.0f32, 5.0f32);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 30, 1.0f32, ETangentMode::Flat);
+        FrameCurve::curve_cubic_splice_frame(&mut curve, 60, 0.0f32, 3.0f32, 3.0f32);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 90, 1.0f32, ETangentMode::Linear);
+        assert_eq!(*curve.cubic_spline_values[0].outtangent(), 5.0);
+        assert_eq!(*curve.cubic_spline_values[1].intangent(), 0.0);
+        assert_eq!(*curve.cubic_spline_values[2].intangent(), 3.0);
+        assert!((curve.cubic_spline_values[3].intangent() - 1.0).abs() < 0.0001);
+
+        // 四元数与颜色的自动切线
+        let mut curve = FrameCurve::curve_cubic_spline(30);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 0, LinearRgba::new(0., 0., 0., 1.), ETangentMode::Auto);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 30, LinearRgba::new(0.5, 1., 0., 1.), ETangentMode::Auto);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 60, LinearRgba::new(1., 2., 0., 1.), ETangentMode::Auto);
+        let color = curve.interple(0.5, &amountcalc);
+        assert!((color.r - 0.25).abs() < 0.0001 && (color.g - 0.5).abs() < 0.0001);
+
+        // sRGB 编码的颜色切线同样是通道斜率, Linear 切线得到直线
+        let mut curve = FrameCurve::curve_cubic_spline(30);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 0, SrgbRgba::new(0.2, 0., 0., 1.), ETangentMode::Linear);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 30, SrgbRgba::new(0.6, 0.4, 0., 0.5), ETangentMode::Linear);
+        for i in 0..=30 {
+            let t = i as KeyFrameCurveValue / 30.;
+            let color = curve.interple(t, &amountcalc);
+            assert!((color.r - (0.2 + 0.4 * t)).abs() < 0.0001 && (color.g - 0.4 * t).abs() < 0.0001);
+            assert!((color.a - (1. - 0.5 * t)).abs() < 0.0001);
+        }
+
+        let half = std::f32::consts::FRAC_1_SQRT_2 as KeyFrameCurveValue;
+        let mut curve = FrameCurve::curve_cubic_spline(30);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 0, Quaternion::identity(), ETangentMode::Auto);
+        FrameCurve::curve_cubic_splice_frame_mode(&mut curve, 30, Quaternion::new(0., 0., half, half), ETangentMode::Auto);
+        let rotation = curve.interple(0.5, &amountcalc);
+        assert!(rotation.w > 0.9 && rotation.z > 0.3);
+    }
+
+    #[test]
+    fn test_quaternion() {
+        let amountcalc = AnimationAmountCalc::default();
+        let half = std::f32::consts::FRAC_1_SQRT_2 as KeyFrameCurveValue;
+
+        let mut curve = FrameCurve::curve_frame_values(30);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, Quaternion::identity());
+        FrameCurve::curve_frame_values_frame(&mut curve, 30, Quaternion::new(0., 0., 1., 0.));
+        let v = curve.interple(0.5, &amountcalc);
+        assert!((v.length() - 1.0).abs() < 0.0001);
+        assert!((v.z - half).abs() < 0.0001 && (v.w - half).abs() < 0.0001);
+
+        // 最短路径: q 与 -q 表示同一旋转
+        let mut curve = FrameCurve::curve_frame_values(30);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, Quaternion::identity());
+        FrameCurve::curve_frame_values_frame(&mut curve, 30, Quaternion::new(0., 0., -half, -half));
+        let v = curve.interple(0.5, &amountcalc);
+        assert!(Quaternion::identity().distance(&v) < std::f32::consts::FRAC_PI_2 as KeyFrameCurveValue);
+
+        let mut curve = FrameCurve::curve_cubic_spline(30);
+        let zero = Quaternion::new(0., 0., 0., 0.);
+        FrameCurve::curve_cubic_splice_frame(&mut curve, 0, Quaternion::identity(), zero, zero);
+        FrameCurve::curve_cubic_splice_frame(&mut curve, 30, Quaternion::new(0., 0., 1., 0.), zero, zero);
+        let v = curve.interple(0.25, &amountcalc);
+        assert!((v.length() - 1.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_frame_bezier() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        let mut curve = FrameCurve::curve_frame_bezier(30);
+        FrameCurve::curve_frame_bezier_frame(&mut curve, 0, 0.0f32, 0., 0., 1., 1.);
+        FrameCurve::curve_frame_bezier_frame(&mut curve, 10, 1.0f32, 0.42, 0., 0.58, 1.);
+        FrameCurve::curve_frame_bezier_frame(&mut curve, 20, 0.0f32, 0., 0., 1., 1.);
+
+        assert!((curve.interple(5. / 30., &amountcalc) - 0.5).abs() < 0.001);
+        assert!((curve.interple(15. / 30., &amountcalc) - 0.5).abs() < 0.001);
+        // ease-in-out 段起始处变化慢于线性
+        assert!(curve.interple(12. / 30., &amountcalc) > 0.8);
+
+        // 通用接口插入的关键帧使用线性控制柄, 控制柄与关键帧保持对应
+        FrameCurve::curve_frame_values_frame(&mut curve, 5, 0.5f32);
+        assert_eq!(curve.bezier_handles.len(), curve.frames.len());
+        assert_eq!(curve.bezier_handles[1], [0., 0., 1., 1.]);
+        assert!((curve.interple(15. / 30., &amountcalc) - 0.5).abs() < 0.001);
+        assert!(curve.interple(12. / 30., &amountcalc) > 0.8);
+    }
+
+    #[test]
+    fn test_mixed_interpolation() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        let mut curve = FrameCurve::curve_mixed(30);
+        FrameCurve::curve_mixed_frame(&mut curve, 0, 0.0f32, 0., 0., EFrameInterpolation::Constant);
+        FrameCurve::curve_mixed_frame(&mut curve, 10, 1.0f32, 0., 0., EFrameInterpolation::Linear);
+        FrameCurve::curve_mixed_frame(&mut curve, 20, 2.0f32, 0., 0., EFrameInterpolation::Hermite);
+        FrameCurve::curve_mixed_frame(&mut curve, 30, 0.0f32, 0., 0., EFrameInterpolation::Bezier(0.42, 0., 0.58, 1.));
+        FrameCurve::curve_mixed_frame(&mut curve, 40, 1.0f32, 0., 0., EFrameInterpolation::Linear);
+
+        assert!((curve.interple(9. / 30., &amountcalc) - 0.0).abs() < 0.0001);
+        assert!((curve.interple(15. / 30., &amountcalc) - 1.5).abs() < 0.0001);
+        assert!((curve.interple(25. / 30., &amountcalc) - 1.0).abs() < 0.0001);
+        assert!((curve.interple(35. / 30., &amountcalc) - 0.5).abs() < 0.001);
+        assert!(curve.interple(32. / 30., &amountcalc) < 0.2);
+
+        // 插值方式随关键帧插入与移动保持对应
+        FrameCurve::curve_cubic_splice_frame(&mut curve, 50, 3.0f32, 0., 0.);
+        assert_eq!(curve.interpolations[5], EFrameInterpolation::Hermite);
+        let index = FrameCurve::curve_cubic_splice_move_frame(&mut curve, 0, 45, 2.0f32);
+        assert_eq!(index, 4);
+        assert_eq!(curve.interpolations.len(), curve.frames.len());
+        assert_eq!(curve.interpolations[0], EFrameInterpolation::Linear);
+        assert_eq!(curve.interpolations[4], EFrameInterpolation::Constant);
+        assert!((curve.interple(48. / 30., &amountcalc) - 2.0).abs() < 0.0001);
+
+        // 插值方式缺失时使用 Hermite
+        curve.interpolations.clear();
+        assert!((curve.interple(15. / 30., &amountcalc) - 1.5).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_weighted_tangent() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        let mut hermite = FrameCurve::curve_minmax_curve(0.0f32, 1.0f32, 30);
+        FrameCurve::curve_minmax_curve_frame(&mut hermite, 0, 0.0, 0.0, 2.0);
+        FrameCurve::curve_minmax_curve_frame(&mut hermite, 30, 1.0, 0.0, 0.0);
+
+        // 权重为 1/3 时与 hermite 插值一致
+        let third = 1. / 3.;
+        let mut weighted = FrameCurve::curve_minmax_curve(0.0f32, 1.0f32, 30);
+        FrameCurve::curve_minmax_curve_frame_weighted(&mut weighted, 0, 0.0, 0.0, 2.0, [third, third], EWeightedMode::Both);
+        FrameCurve::curve_minmax_curve_frame_weighted(&mut weighted, 30, 1.0, 0.0, 0.0, [third, third], EWeightedMode::Both);
+
+        for i in 0..=30 {
+            let t = i as KeyFrameCurveValue / 30.;
+            assert!((hermite.interple(t, &amountcalc) - weighted.interple(t, &amountcalc)).abs() < 0.001);
+        }
+
+        // 更大的 out 权重使曲线更早接近目标值
+        let mut heavy = FrameCurve::curve_minmax_curve(0.0f32, 1.0f32, 30);
+        FrameCurve::curve_minmax_curve_frame_weighted(&mut heavy, 0, 0.0, 0.0, 2.0, [third, 0.6], EWeightedMode::Out);
+        FrameCurve::curve_minmax_curve_frame(&mut heavy, 30, 1.0, 0.0, 0.0);
+        assert!(heavy.interple(0.25, &amountcalc) > hermite.interple(0.25, &amountcalc));
+        assert!((heavy.interple(1.0, &amountcalc) - 1.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_fractional_frame() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        // 目标帧刚越过关键帧时应进入下一区间
+        let mut curve = FrameCurve::curve_frame_values(30);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, 0.0f32);
+        FrameCurve::curve_frame_values_frame(&mut curve, 15, 1.0f32);
+        FrameCurve::curve_frame_values_frame(&mut curve, 30, 3.0f32);
+        assert!((curve.interple(15.0 / 30., &amountcalc) - 1.0).abs() < 0.0001);
+        assert!((curve.interple(15.5 / 30., &amountcalc) - (1.0 + 1.0 / 15.)).abs() < 0.0001);
+
+        // 小数帧关键帧 (如以秒为单位的 GLTF 数据)
+        let mut curve = FrameCurve::curve_frame_values(30);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0.25 * 30., 0.0f32);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0.35 * 30., 1.0f32);
+        assert_eq!(curve.min_frame, 0.25 * 30.);
+        assert!((curve.frame_number - 3.0).abs() < 0.0001);
+        assert!((curve.interple(0.3, &amountcalc) - 0.5).abs() < 0.0001);
+        assert_eq!(FrameCurve::<f32>::get_pre_next_frame_index(&curve.frames, 8.0), (0, 1));
+        assert_eq!(FrameCurve::<f32>::get_pre_next_frame_index(&curve.frames, 7.0), (0, 0));
+        assert_eq!(FrameCurve::<f32>::get_pre_next_frame_index(&curve.frames, 11.0), (1, 1));
+    }
+
+    #[test]
+    fn test_wrap_mode() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        let mut curve = FrameCurve::curve_frame_values(10);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, 0.0f32);
+        FrameCurve::curve_frame_values_frame(&mut curve, 10, 1.0f32);
+
+        // 默认保持端点数值
+        assert!((curve.interple(1.2, &amountcalc) - 1.0).abs() < 0.0001);
+        assert!((curve.interple(-0.2, &amountcalc) - 0.0).abs() < 0.0001);
+
+        curve.set_wrap_mode(ECurveWrapMode::Loop, ECurveWrapMode::Loop);
+        assert!((curve.interple(1.2, &amountcalc) - 0.2).abs() < 0.0001);
+        assert!((curve.interple(-0.2, &amountcalc) - 0.8).abs() < 0.0001);
+
+        curve.set_wrap_mode(ECurveWrapMode::Constant, ECurveWrapMode::PingPong);
+        assert!((curve.interple(1.2, &amountcalc) - 0.8).abs() < 0.0001);
+        assert!((curve.interple(-0.2, &amountcalc) - 0.0).abs() < 0.0001);
+
+        curve.set_wrap_mode(ECurveWrapMode::CycleWithOffset, ECurveWrapMode::CycleWithOffset);
+        assert!((curve.interple(1.2, &amountcalc) - 1.2).abs() < 0.0001);
+        assert!((curve.interple(2.5, &amountcalc) - 2.5).abs() < 0.0001);
+        assert!((curve.interple(-0.2, &amountcalc) - -0.2).abs() < 0.0001);
+
+        curve.set_wrap_mode(ECurveWrapMode::Linear, ECurveWrapMode::Linear);
+        assert!((curve.interple(1.2, &amountcalc) - 1.2).abs() < 0.001);
+        assert!((curve.interple(-0.2, &amountcalc) - -0.2).abs() < 0.001);
+
+        // 线性外插沿端点切线方向
+        let mut curve = FrameCurve::curve_cubic_spline(10);
+        FrameCurve::curve_cubic_splice_frame(&mut curve, 0, 0.0f32, 0.0, 0.0);
+        FrameCurve::curve_cubic_splice_frame(&mut curve, 10, 1.0f32, 3.0, 0.0);
+        curve.set_wrap_mode(ECurveWrapMode::Constant, ECurveWrapMode::Linear);
+        assert!((curve.interple(1.5, &amountcalc) - 2.5).abs() < 0.0001);
+
+        // MinMaxCurve 的切线单位为 数值/区间
+        let mut curve = FrameCurve::curve_minmax_curve(0.0f32, 1.0f32, 10);
+        FrameCurve::curve_minmax_curve_frame(&mut curve, 0, 0.0, 0.0, 0.0);
+        FrameCurve::curve_minmax_curve_frame(&mut curve, 10, 1.0, 2.0, 0.0);
+        curve.set_wrap_mode(ECurveWrapMode::Linear, ECurveWrapMode::Linear);
+        assert!((curve.interple(1.5, &amountcalc) - 2.0).abs() < 0.0001);
+        assert!((curve.interple(-0.5, &amountcalc) - 0.0).abs() < 0.0001);
+
+        // 步进曲线与缓入缓出的贝塞尔段水平外插
+        let mut curve = FrameCurve::curve_frame_values_step(10, EFrameStepMode::Previous);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, 0.0f32);
+        FrameCurve::curve_frame_values_frame(&mut curve, 10, 1.0f32);
+        curve.set_wrap_mode(ECurveWrapMode::Linear, ECurveWrapMode::Linear);
+        assert_eq!(curve.interple(1.5, &amountcalc), 1.0);
+        assert_eq!(curve.interple(-0.5, &amountcalc), 0.0);
+
+        let mut curve = FrameCurve::curve_frame_bezier(10);
+        FrameCurve::curve_frame_bezier_frame(&mut curve, 0, 0.0f32, 0.42, 0.0, 0.58, 1.0);
+        FrameCurve::curve_frame_bezier_frame(&mut curve, 10, 1.0f32, 0.42, 0.0, 0.58, 1.0);
+        curve.set_wrap_mode(ECurveWrapMode::Linear, ECurveWrapMode::Linear);
+        assert!((curve.interple(1.5, &amountcalc) - 1.0).abs() < 0.0001);
+        assert!((curve.interple(-0.5, &amountcalc) - 0.0).abs() < 0.0001);
+
+        // 整数数值的斜率不被取整
+        let mut curve = FrameCurve::curve_frame_values(10);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, 0u8);
+        FrameCurve::curve_frame_values_frame(&mut curve, 10, 100u8);
+        curve.set_wrap_mode(ECurveWrapMode::Constant, ECurveWrapMode::Linear);
+        assert_eq!(curve.interple(1.2, &amountcalc), 120);
+    }
+
+    #[test]
+    fn test_spring_amount() {
+        // 欠阻尼 存在过冲
+        let amountcalc = AnimationAmountCalc::from_spring(1.0, 100.0, 10.0, 0.0);
+        let duration = amountcalc.spring_duration().unwrap();
+        assert!((duration - 1.4107).abs() < 0.001);
+        assert!(amountcalc.calc(0.0).abs() < 0.0001);
+        assert!((amountcalc.calc(1.0) - 1.0).abs() < 0.0001);
+        let peak = (0..=100).map(|i| amountcalc.calc(i as KeyFrameCurveValue / 100.)).fold(0.0, KeyFrameCurveValue::max);
+        assert!(peak > 1.1);
+        // 稳定时长之后位移不超过阈值
+        assert!((amountcalc.calc(0.99) - 1.0).abs() <= SPRING_SETTLING_THRESHOLD);
+
+        // 临界阻尼 / 过阻尼 单调无过冲
+        for damping in [20.0, 40.0] {
+            let amountcalc = AnimationAmountCalc::from_spring(1.0, 100.0, damping, 0.0);
+            let duration = amountcalc.spring_duration().unwrap();
+            assert!(spring_displacement(1.0, 100.0, damping, 0.0, duration).abs() <= SPRING_SETTLING_THRESHOLD * 1.01);
+            let mut last = 0.0;
+            for i in 0..=100 {
+                let v = amountcalc.calc(i as KeyFrameCurveValue / 100.);
+                assert!(v >= last - 0.0001 && v <= 1.0001);
+                last = v;
+            }
+        }
+
+        // 初始速度
+        let amountcalc = AnimationAmountCalc::from_spring(1.0, 100.0, 20.0, 10.0);
+        assert!(amountcalc.calc(0.05) > AnimationAmountCalc::from_spring(1.0, 100.0, 20.0, 0.0).calc(0.05));
+
+        assert!(AnimationAmountCalc::from_spring(0.0, 100.0, 10.0, 0.0).spring_duration().is_none());
+    }
+
+    #[test]
+    fn test_linear_stops_amount() {
+        // linear(0, 0.25, 1)
+        let amountcalc = AnimationAmountCalc::from_linear_stops(&[(0.0, None), (0.25, None), (1.0, None)]);
+        assert!((amountcalc.calc(0.25) - 0.125).abs() < 0.0001);
+        assert!((amountcalc.calc(0.75) - 0.625).abs() < 0.0001);
+        assert!((amountcalc.calc(1.0) - 1.0).abs() < 0.0001);
+        // 区间外沿首尾两段外插
+        assert!((amountcalc.calc(1.2) - 1.3).abs() < 0.0001);
+
+        // linear(0, 1 50%, 0.5 40%, 1): 输入小于之前输入时取之前的最大输入, 形成跳变
+        let amountcalc = AnimationAmountCalc::from_linear_stops(&[(0.0, None), (1.0, Some(0.5)), (0.5, Some(0.4)), (1.0, None)]);
+        assert!((amountcalc.calc(0.25) - 0.5).abs() < 0.0001);
+        assert!((amountcalc.calc(0.5) - 0.5).abs() < 0.0001);
+        assert!((amountcalc.calc(0.75) - 0.75).abs() < 0.0001);
+
+        // linear(0, 0.2, 0.4 80%, 1): 中间缺省输入在两侧之间均分
+        let amountcalc = AnimationAmountCalc::from_linear_stops(&[(0.0, None), (0.2, None), (0.4, Some(0.8)), (1.0, None)]);
+        assert!((amountcalc.calc(0.4) - 0.2).abs() < 0.0001);
+        assert!((amountcalc.calc(0.9) - 0.7).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_frame_values_step() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        let check = |mode: EFrameStepMode, expect: [f32; 5]| {
+            let mut curve = FrameCurve::curve_frame_values_step(10, mode);
+            FrameCurve::curve_frame_values_frame(&mut curve, 0, 0.0f32);
+            FrameCurve::curve_frame_values_frame(&mut curve, 10, 1.0f32);
+            FrameCurve::curve_frame_values_frame(&mut curve, 20, 2.0f32);
+            assert_eq!(curve.frame_step_mode(), mode);
+            for (i, time) in [0.0, 0.2, 0.8, 1.0, 1.5].iter().enumerate() {
+                assert_eq!(curve.interple(*time, &amountcalc), expect[i]);
+            }
+        };
+
+        check(EFrameStepMode::Previous, [0.0, 0.0, 0.0, 1.0, 1.0]);
+        check(EFrameStepMode::Next, [0.0, 1.0, 1.0, 1.0, 2.0]);
+        check(EFrameStepMode::Nearest, [0.0, 0.0, 1.0, 1.0, 2.0]);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Vec2(KeyFrameCurveValue, KeyFrameCurveValue);
+    impl std::ops::Add for Vec2 {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Vec2(self.0 + rhs.0, self.1 + rhs.1)
+        }
+    }
+    impl FrameValueScale for Vec2 {
+        fn scale(&self, rhs: KeyFrameCurveValue) -> Self {
+            Vec2(self.0 * rhs, self.1 * rhs)
+        }
+    }
+    impl FrameValueDistance for Vec2 {
+        fn distance(&self, rhs: &Self) -> KeyFrameCurveValue {
+            ((self.0 - rhs.0).powi(2) + (self.1 - rhs.1).powi(2)).sqrt()
+        }
+    }
+
+    #[test]
+    fn test_path() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        // 关键点间距不均匀时仍然匀速
+        let mut curve = FrameCurve::curve_path(10, 10);
+        curve.curve_path_point(0.0f32, 1.0, 1.0);
+        curve.curve_path_point(1.0f32, 1.0, 9.0);
+        curve.curve_path_point(10.0f32, 9.0, 9.0);
+        assert!((curve.path_length() - 10.0).abs() < 0.0001);
+        assert!((curve.interple(0.05, &amountcalc) - 0.5).abs() < 0.001);
+        assert!((curve.interple(0.5, &amountcalc) - 5.0).abs() < 0.001);
+        assert!((curve.interple(1.0, &amountcalc) - 10.0).abs() < 0.001);
+
+        // 2D 路径 位置与切线方向
+        let mut curve = FrameCurve::curve_path(10, 10);
+        curve.curve_path_point(Vec2(0., 0.), Vec2(1., 0.), Vec2(1., 0.));
+        curve.curve_path_point(Vec2(1., 0.), Vec2(1., 0.), Vec2(0., 3.));
+        curve.curve_path_point(Vec2(1., 3.), Vec2(0., 3.), Vec2(0., 3.));
+        assert!((curve.path_length() - 4.0).abs() < 0.0001);
+
+        let (position, tangent) = curve.interple_path(0.125, &amountcalc);
+        assert!(position.distance(&Vec2(0.5, 0.)) < 0.001);
+        assert!(tangent.distance(&Vec2(1., 0.)) < 0.001);
+
+        let (position, tangent) = curve.interple_path(0.5, &amountcalc);
+        assert!(position.distance(&Vec2(1., 1.)) < 0.001);
+        assert!(tangent.distance(&Vec2(0., 1.)) < 0.001);
+
+        // 往返时切线反向
+        curve.set_wrap_mode(ECurveWrapMode::Constant, ECurveWrapMode::PingPong);
+        let (position, tangent) = curve.interple_path(1.5, &amountcalc);
+        assert!(position.distance(&Vec2(1., 1.)) < 0.001);
+        assert!(tangent.distance(&Vec2(0., -1.)) < 0.001);
+
+        // 3D 路径 使用数组作为路径点
+        let mut curve = FrameCurve::curve_path(10, 10);
+        curve.curve_path_point([0.0f32, 0., 0.], [0., 0., 2.], [0., 0., 2.]);
+        curve.curve_path_point([0.0f32, 0., 2.], [0., 0., 2.], [0., 2., 0.]);
+        curve.curve_path_point([0.0f32, 2., 2.], [0., 2., 0.], [0., 2., 0.]);
+        assert!((curve.path_length() - 4.0).abs() < 0.0001);
+
+        let (position, tangent) = curve.interple_path(0.75, &amountcalc);
+        assert!(position.distance(&[0., 1., 2.]) < 0.001);
+        assert!(tangent.distance(&[0., 1., 0.]) < 0.001);
+    }
+
+    #[test]
+    fn test_noise_curve() {
+        let amountcalc = AnimationAmountCalc::default();
+        let param = NoiseParam { seed: 7, frequency: 2.0, octaves: 3, amplitude: 0.5 };
+
+        let curve = FrameCurve::curve_noise(10.0f32, 4.0f32, 60, 60, param);
+        let other = FrameCurve::curve_noise(10.0f32, 4.0f32, 60, 60, param);
+        let reseed = FrameCurve::curve_noise(10.0f32, 4.0f32, 60, 60, NoiseParam { seed: 8, ..param });
+
+        let mut last = curve.interple(0.0, &amountcalc);
+        let mut differs = false;
+        for i in 0..600 {
+            let time = i as KeyFrameCurveValue / 120.;
+            let v = curve.interple(time, &amountcalc);
+            // 相同种子结果一致
+            assert_eq!(v, other.interple(time, &amountcalc));
+            // 范围 from ± scalar * amplitude
+            assert!((8.0..=12.0).contains(&v));
+            // 连续
+            assert!((v - last).abs() < 0.5);
+            differs |= v != reseed.interple(time, &amountcalc);
+            last = v;
+        }
+        assert!(differs);
+
+        // 噪声参数只存在于噪声曲线
+        assert_eq!(curve.noise_param(), Some(param));
+        assert_eq!(FrameCurve::<f32>::curve_frame_values(60).noise_param(), None);
+
+        // 单层噪声在整数位置为 0
+        assert_eq!(gradient_noise(3, 5.0), 0.0);
+    }
+
+    #[test]
+    fn test_oscillator_curve() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        // 周期 20 帧 (60 帧/秒), 数值 1 + 2 * wave
+        let check = |waveform: EWaveform, expect: [f32; 4]| {
+            let curve = FrameCurve::curve_oscillator(1.0f32, 2.0f32, 60, waveform, 20., 0., 1., 0.);
+            for (i, frame) in [0., 5., 12., 45.].iter().enumerate() {
+                assert!((curve.interple(frame / 60., &amountcalc) - expect[i]).abs() < 0.0001, "{:?} {}", waveform, frame);
+            }
+        };
+        check(EWaveform::Sine, [1.0, 3.0, 1.0 - 2.0 * (0.2 * std::f32::consts::PI).sin(), 3.0]);
+        check(EWaveform::Triangle, [1.0, 3.0, 0.2, 3.0]);
+        check(EWaveform::Square, [3.0, 3.0, -1.0, 3.0]);
+        check(EWaveform::Sawtooth, [1.0, 2.0, -0.6, 2.0]);
+
+        // 相位 / 振幅 / 偏移
+        let curve = FrameCurve::curve_oscillator(0.0f32, 1.0f32, 60, EWaveform::Sine, 20., 0.25, 0.5, 0.5);
+        assert!((curve.interple(0., &amountcalc) - 1.0).abs() < 0.0001);
+        assert!((curve.interple(10. / 60., &amountcalc) - 0.0).abs() < 0.0001);
+        assert_eq!(curve.waveform(), Some(EWaveform::Sine));
+        assert_eq!(curve.oscillator_args(), Some([20., 0.25, 0.5, 0.5]));
+        assert_eq!(FrameCurve::<f32>::curve_frame_values(60).waveform(), None);
+    }
+
+    #[test]
+    fn test_composite_curve() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        let ramp = || {
+            let mut curve = FrameCurve::curve_frame_values(10);
+            FrameCurve::curve_frame_values_frame(&mut curve, 0, 0.0f32);
+            FrameCurve::curve_frame_values_frame(&mut curve, 20, 2.0f32);
+            curve
+        };
+
+        // 开场缓动 + 循环主体
+        let mut body = FrameCurve::curve_frame_values(10);
+        FrameCurve::curve_frame_values_frame(&mut body, 0, 1.0f32);
+        FrameCurve::curve_frame_values_frame(&mut body, 10, 2.0f32);
+        FrameCurve::curve_frame_values_frame(&mut body, 20, 1.0f32);
+        body.set_wrap_mode(ECurveWrapMode::Constant, ECurveWrapMode::Loop);
+
+        let mut curve = CompositeCurve::sequence(30);
+        curve.add_child(FrameCurve::curve_easing(0.0f32, 1.0f32, 30, 30, EEasingMode::None));
+        curve.add_child(body);
+        assert_eq!(curve.mode(), ECompositeMode::Sequence);
+        assert!((curve.frame_number - 90.0).abs() < 0.0001);
+        assert!((curve.interple(0.5, &amountcalc) - 0.5).abs() < 0.0001);
+        assert!((curve.interple(1.5, &amountcalc) - 1.5).abs() < 0.0001);
+        assert!((curve.interple(2.0, &amountcalc) - 2.0).abs() < 0.0001);
+        assert!((curve.interple(3.5, &amountcalc) - 1.5).abs() < 0.0001);
+
+        // 叠加
+        let mut curve = CompositeCurve::sum(30);
+        curve.add_child(ramp());
+        curve.add_child(FrameCurve::curve_oscillator(0.0f32, 1.0f32, 30, EWaveform::Square, 30., 0., 0.5, 0.));
+        assert!((curve.frame_number - 60.0).abs() < 0.0001);
+        assert!((curve.interple(0.25, &amountcalc) - 0.75).abs() < 0.0001);
+        assert!((curve.interple(1.75, &amountcalc) - 1.25).abs() < 0.0001);
+
+        // 相乘
+        let mut curve = CompositeCurve::product(30);
+        curve.add_child(ramp());
+        curve.add_child(ramp());
+        assert!((curve.interple(1.5, &amountcalc) - 2.25).abs() < 0.0001);
+
+        // 时间重映射: 2 倍速
+        let mut remap = FrameCurve::curve_frame_values(10);
+        FrameCurve::curve_frame_values_frame(&mut remap, 0, 0.0 as KeyFrameCurveValue);
+        FrameCurve::curve_frame_values_frame(&mut remap, 10, 2.0 as KeyFrameCurveValue);
+        let curve = CompositeCurve::remap(30, remap, ramp());
+        assert!((curve.frame_number - 30.0).abs() < 0.0001);
+        assert!((curve.interple(0.25, &amountcalc) - 0.5).abs() < 0.0001);
+        assert!((curve.interple(1.0, &amountcalc) - 2.0).abs() < 0.0001);
+
+        // 没有子曲线时返回起始值, 未设置时为默认值
+        let mut curve = CompositeCurve::<f32>::sequence(30);
+        assert_eq!(curve.interple(0.5, &amountcalc), 0.0);
+        curve.value_offset = Some(3.0);
+        assert_eq!(curve.interple(0.5, &amountcalc), 3.0);
+        assert_eq!(CompositeCurve::<f32>::sum(30).interple(0.5, &amountcalc), 0.0);
+        assert_eq!(CompositeCurve::<f32>::product(30).interple(0.5, &amountcalc), 0.0);
+    }
+
+    #[test]
+    fn test_curve_modifier() {
+        let amountcalc = AnimationAmountCalc::default();
+        let base = || {
+            let mut curve = FrameCurve::curve_frame_values(10);
+            FrameCurve::curve_frame_values_frame(&mut curve, 0, 0.0f32);
+            FrameCurve::curve_frame_values_frame(&mut curve, 10, 1.0f32);
+            curve
+        };
+
+        // 时间修改器
+        let mut curve = base();
+        assert!(curve.modifiers().is_empty());
+        curve.add_modifier(CurveModifier::new(ECurveModifier::Cycles { before: ECurveWrapMode::Loop, after: ECurveWrapMode::Loop }));
+        curve.add_modifier(CurveModifier::new(ECurveModifier::Stepped { step: 5., offset: 0. }));
+        assert!((curve.interple(0.7, &amountcalc) - 0.5).abs() < 0.0001);
+        assert!((curve.interple(1.7, &amountcalc) - 0.5).abs() < 0.0001);
+        assert!((curve.interple(-0.2, &amountcalc) - 0.5).abs() < 0.0001);
+        assert_eq!(curve.modifiers().len(), 2);
+
+        let mut curve = base();
+        curve.add_modifier(CurveModifier::new(ECurveModifier::Cycles { before: ECurveWrapMode::Constant, after: ECurveWrapMode::CycleWithOffset }));
+        assert!((curve.interple(2.5, &amountcalc) - 2.5).abs() < 0.0001);
+
+        let mut curve = base();
+        curve.add_modifier(CurveModifier::new(ECurveModifier::LimitTime { min: 0., max: 5. }));
+        assert!((curve.interple(0.9, &amountcalc) - 0.5).abs() < 0.0001);
+
+        // 数值修改器
+        let mut curve = base();
+        curve.add_modifier(CurveModifier::new(ECurveModifier::Generator { coefficients: vec![1.0, 2.0], additive: false }));
+        assert!((curve.interple(0.5, &amountcalc) - 2.0).abs() < 0.0001);
+        curve.modifier_mut(0).unwrap().mode = ECurveModifier::Generator { coefficients: vec![1.0, 2.0], additive: true };
+        assert!((curve.interple(0.5, &amountcalc) - 2.5).abs() < 0.0001);
+
+        let mut curve = base();
+        curve.add_modifier(CurveModifier::new(ECurveModifier::Envelope { reference: 0.5, points: vec![(0., 1.), (10., 3.)] }));
+        assert!((curve.interple(1.0, &amountcalc) - 2.0).abs() < 0.0001);
+
+        let mut curve = base();
+        let index = curve.add_modifier(CurveModifier::limit_value(0.2, 0.6));
+        assert!((curve.interple(0.9, &amountcalc) - 0.6).abs() < 0.0001);
+        assert!((curve.interple(0.1, &amountcalc) - 0.2).abs() < 0.0001);
+
+        // 影响权重 / 静音
+        curve.modifier_mut(index).unwrap().influence = 0.5;
+        assert!((curve.interple(0.9, &amountcalc) - 0.75).abs() < 0.0001);
+        curve.modifier_mut(index).unwrap().mute = true;
+        assert!((curve.interple(0.9, &amountcalc) - 0.9).abs() < 0.0001);
+
+        // 噪声修改器与噪声曲线一致
+        let param = NoiseParam { seed: 3, frequency: 2.0, octaves: 2, amplitude: 1.0 };
+        let mut curve = base();
+        curve.add_modifier(CurveModifier::new(ECurveModifier::Noise { param, scalar: 0.5 }));
+        let noise = FrameCurve::curve_noise(0.0f32, 0.5f32, 10, 10, param);
+        assert!((curve.interple(0.3, &amountcalc) - (0.3 + noise.interple(0.3, &amountcalc))).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_minmax_curve_mode() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        let curve = FrameCurve::curve_minmax_constant(0.0f32, 2.0f32, 0.5, 60);
+        assert_eq!(curve.minmax_curve_mode(), EMinMaxCurveMode::Constant);
+        assert!((curve.interple(0.3, &amountcalc) - 1.0).abs() < 0.0001);
+        assert!((curve.interple_random(0.3, &amountcalc, 0.2) - 1.0).abs() < 0.0001);
+
+        let curve = FrameCurve::curve_minmax_two_constants(0.0f32, 1.0f32, 0.2, 0.8, 60);
+        assert_eq!(curve.minmax_curve_mode(), EMinMaxCurveMode::TwoConstants);
+        assert!((curve.interple_random(0.3, &amountcalc, 0.0) - 0.2).abs() < 0.0001);
+        assert!((curve.interple_random(0.3, &amountcalc, 0.5) - 0.5).abs() < 0.0001);
+        assert!((curve.interple_random(0.3, &amountcalc, 1.0) - 0.8).abs() < 0.0001);
+        assert!((curve.interple(0.3, &amountcalc) - 0.8).abs() < 0.0001);
+
+        // 曲线倍数
+        let mut curve = FrameCurve::curve_minmax_curve(0.0f32, 1.0f32, 60);
+        FrameCurve::curve_minmax_curve_frame(&mut curve, 0, 0.0, 1.0, 1.0);
+        FrameCurve::curve_minmax_curve_frame(&mut curve, 60, 1.0, 1.0, 1.0);
+        curve.set_minmax_curve_multiplier(2.0);
+        assert!((curve.interple(0.5, &amountcalc) - 1.0).abs() < 0.0001);
+        assert!((curve.interple_random(0.5, &amountcalc, 0.0) - 1.0).abs() < 0.0001);
+
+        // 两条曲线, 帧范围取并集
+        let mut curve = FrameCurve::curve_minmax_two_curves(0.0f32, 1.0f32, 60);
+        FrameCurve::curve_minmax_curve_frame(&mut curve, 0, 0.0, 0.5, 0.5);
+        FrameCurve::curve_minmax_curve_frame(&mut curve, 30, 0.5, 0.5, 0.5);
+        curve.curve_minmax_curve_min_frame(0, 0.0, 0.5, 0.5);
+        curve.curve_minmax_curve_min_frame(60, 0.5, 0.5, 0.5);
+        assert!((curve.max_frame - 60.0).abs() < 0.0001);
+        assert!((curve.interple_random(0.75, &amountcalc, 1.0) - 0.5).abs() < 0.0001);
+        assert!((curve.interple_random(0.75, &amountcalc, 0.0) - 0.375).abs() < 0.0001);
+        assert!((curve.interple_random(0.75, &amountcalc, 0.5) - 0.4375).abs() < 0.0001);
+        curve.set_minmax_curve_multiplier(2.0);
+        assert!((curve.interple_random(0.75, &amountcalc, 0.5) - 0.875).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_color_interpolation() {
+        let amountcalc = AnimationAmountCalc::default();
+        let close = |a: [KeyFrameCurveValue; 4], b: [KeyFrameCurveValue; 4]| a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 0.002);
+        let srgb = |c: SrgbRgba| [c.r, c.g, c.b, c.a];
+
+        let red = SrgbRgba::new(1., 0., 0., 1.);
+        let blue = SrgbRgba::new(0., 0., 1., 0.);
+
+        // sRGB 空间逐通道插值
+        assert!(close(srgb(red.interpolate(&blue, 0.5)), [0.5, 0., 0.5, 0.5]));
+        // 线性空间插值, 转回 sRGB 更亮
+        let mixed = red.with_space(EColorSpace::LinearRgb).interpolate(&blue, 0.5);
+        assert!(close(srgb(mixed), [0.7354, 0., 0.7354, 0.5]));
+        // HSV 色相走最短路径: 红(0) -> 蓝(2/3) 经过品红
+        let mixed = red.with_space(EColorSpace::Hsv).interpolate(&blue, 0.5);
+        assert!(close(srgb(mixed), [1., 0., 1., 0.5]));
+        // 灰色的色相取另一端
+        let gray = SrgbRgba::new(0.5, 0.5, 0.5, 1.).with_space(EColorSpace::Hsv);
+        let mixed = gray.interpolate(&SrgbRgba::new(1., 0., 0., 1.), 0.5);
+        assert!(close(srgb(mixed), [0.75, 0.375, 0.375, 1.]));
+        // OKLab 首尾不变, 白到黑中点的 sRGB 亮度接近感知中灰
+        let white = SrgbRgba::new(1., 1., 1., 1.).with_space(EColorSpace::OkLab);
+        let black = SrgbRgba::new(0., 0., 0., 1.);
+        assert!(close(srgb(white.interpolate(&black, 0.0)), [1., 1., 1., 1.]));
+        assert!(close(srgb(white.interpolate(&black, 1.0)), [0., 0., 0., 1.]));
+        let mixed = white.interpolate(&black, 0.5);
+        assert!((mixed.r - 0.3885).abs() < 0.002 && (mixed.r - mixed.b).abs() < 0.0001);
+
+        // 编码转换与 8 位通道
+        let color = SrgbRgba::from_u8(255, 128, 0, 255);
+        assert_eq!(color.to_linear().to_srgb().to_u8(), [255, 128, 0, 255]);
+        assert!((color.to_linear().g - 0.2158).abs() < 0.001);
+
+        // 作为关键帧曲线数值
+        let mut curve = FrameCurve::curve_frame_values(10);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, LinearRgba::new(1., 0., 0., 1.));
+        FrameCurve::curve_frame_values_frame(&mut curve, 10, LinearRgba::new(0., 0., 1., 1.));
+        let value = curve.interple(0.5, &amountcalc);
+        assert!((value.r - 0.5).abs() < 0.0001 && (value.b - 0.5).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_gradient() {
+        let amountcalc = AnimationAmountCalc::default();
+        let close = |c: SrgbRgba, b: [KeyFrameCurveValue; 4]| [c.r, c.g, c.b, c.a].iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 0.0001);
+
+        // 粒子生命周期 60 帧, 60 FPS
+        let mut curve = FrameCurve::curve_gradient(60, 60, EGradientMode::Blend);
+        curve.curve_gradient_color_key(0.0, SrgbRgba::new(1., 0., 0., 1.));
+        curve.curve_gradient_color_key(0.5, SrgbRgba::new(0., 1., 0., 1.));
+        curve.curve_gradient_color_key(1.0, SrgbRgba::new(0., 0., 1., 1.));
+        assert!(close(curve.interple(0.25, &amountcalc), [0.5, 0.5, 0., 1.]));
+        assert!(close(curve.interple(0.75, &amountcalc), [0., 0.5, 0.5, 1.]));
+        // 区间外取首尾颜色
+        assert!(close(curve.interple(2.0, &amountcalc), [0., 0., 1., 1.]));
+
+        // 透明度关键帧独立于颜色关键帧
+        curve.curve_gradient_alpha_key(0.2, 1.0);
+        curve.curve_gradient_alpha_key(1.0, 0.0);
+        assert!(close(curve.interple(0.1, &amountcalc), [0.8, 0.2, 0., 1.]));
+        assert!(close(curve.interple(0.6, &amountcalc), [0., 0.8, 0.2, 0.5]));
+
+        // Fixed 模式取时间不小于目标时间的关键帧
+        let mut curve = FrameCurve::curve_gradient(60, 60, EGradientMode::Fixed);
+        curve.curve_gradient_color_key(0.0, SrgbRgba::new(1., 0., 0., 1.));
+        curve.curve_gradient_color_key(0.5, SrgbRgba::new(0., 1., 0., 1.));
+        curve.curve_gradient_color_key(1.0, SrgbRgba::new(0., 0., 1., 1.));
+        assert_eq!(curve.gradient_mode(), EGradientMode::Fixed);
+        assert!(close(curve.interple(0.25, &amountcalc), [0., 1., 0., 1.]));
+        assert!(close(curve.interple(0.5, &amountcalc), [0., 1., 0., 1.]));
+        assert!(close(curve.interple(0.75, &amountcalc), [0., 0., 1., 1.]));
+
+        // 没有颜色关键帧时为不透明白色, 仍可叠加透明度关键帧
+        let mut curve = FrameCurve::<SrgbRgba>::curve_gradient(10, 10, EGradientMode::Blend);
+        assert!(close(curve.interple(0.5, &amountcalc), [1., 1., 1., 1.]));
+        curve.curve_gradient_alpha_key(0.0, 1.0);
+        curve.curve_gradient_alpha_key(1.0, 0.0);
+        assert!(close(curve.interple(0.5, &amountcalc), [1., 1., 1., 0.5]));
+    }
+
+    #[test]
+    fn test_angle_interpolation() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        // 350° -> 10° 经过 0°
+        let mut curve = FrameCurve::curve_frame_values(60);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, Degrees(350.));
+        FrameCurve::curve_frame_values_frame(&mut curve, 60, Degrees(10.));
+        assert!((curve.interple(0.5, &amountcalc).wrapped().0 - 0.).abs() < 0.0001);
+        assert!((curve.interple(0.25, &amountcalc).0 - 355.).abs() < 0.0001);
+        assert!((curve.interple(1.0, &amountcalc).wrapped().0 - 10.).abs() < 0.0001);
+        assert!((Degrees(350.).distance(&Degrees(10.)) - 20.).abs() < 0.0001);
+
+        // 弧度, 反方向跨越
+        let value = Radians(0.1).interpolate(&Radians(Radians::PERIOD - 0.1), 0.5);
+        assert!(value.0.abs() < 0.0001);
+        assert!((Degrees(90.).to_radians().0 - std::f32::consts::FRAC_PI_2 as KeyFrameCurveValue).abs() < 0.0001);
+
+        // 三次样条在环绕处保持连续
+        let mut curve = FrameCurve::curve_cubic_spline(60);
+        curve.curve_cubic_splice_frame_mode(0, Degrees(340.), ETangentMode::Auto);
+        curve.curve_cubic_splice_frame_mode(30, Degrees(350.), ETangentMode::Auto);
+        curve.curve_cubic_splice_frame_mode(60, Degrees(0.), ETangentMode::Auto);
+        curve.curve_cubic_splice_frame_mode(90, Degrees(10.), ETangentMode::Auto);
+        let value = curve.interple(0.75, &amountcalc).wrapped().0;
+        assert!((value - 355.).abs() < 0.5);
+
+        // 展开关键帧序列
+        let mut keys = [Degrees(350.), Degrees(10.), Degrees(30.), Degrees(200.)];
+        Degrees::unwrap(&mut keys);
+        assert_eq!(keys.map(|v| v.0), [350., 370., 390., 560.]);
+        let mut values = [170., -170., 180.];
+        unwrap_angles(&mut values, 360.);
+        assert_eq!(values, [170., 190., 180.]);
+    }
+
+    #[test]
+    fn test_array_tuple_values() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        // 3D 位置
+        let mut curve = FrameCurve::curve_frame_values(60);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, [0.0 as KeyFrameCurveValue, 1.0, 2.0]);
+        FrameCurve::curve_frame_values_frame(&mut curve, 60, [2.0, 3.0, 4.0]);
+        let value = curve.interple(0.5, &amountcalc);
+        assert!(value.iter().zip([1.0, 2.0, 3.0].iter()).all(|(a, b)| (a - b).abs() < 0.0001));
+        assert!(([0.0 as KeyFrameCurveValue, 0.0].distance(&[3.0, 4.0]) - 5.0).abs() < 0.0001);
+
+        // 数组的切线逐分量计算
+        let mut curve = FrameCurve::curve_cubic_spline(60);
+        curve.curve_cubic_splice_frame_mode(0, [0.0 as KeyFrameCurveValue, 0.0], ETangentMode::Linear);
+        curve.curve_cubic_splice_frame_mode(60, [1.0, -2.0], ETangentMode::Linear);
+        let value = curve.interple(0.25, &amountcalc);
+        assert!((value[0] - 0.25).abs() < 0.0001 && (value[1] + 0.5).abs() < 0.0001);
+
+        // 元组, 分量可以是不同类型
+        let mut curve = FrameCurve::curve_frame_values(60);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, (0.0 as KeyFrameCurveValue, Degrees(350.)));
+        FrameCurve::curve_frame_values_frame(&mut curve, 60, (1.0, Degrees(10.)));
+        let (value, angle) = curve.interple(0.5, &amountcalc);
+        assert!((value - 0.5).abs() < 0.0001 && (angle.wrapped().0 - 0.).abs() < 0.0001);
+        assert!(((0.0 as KeyFrameCurveValue, 0.0 as KeyFrameCurveValue).distance(&(3.0, 4.0)) - 5.0).abs() < 0.0001);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra_values() {
+        use nalgebra::{Point3, UnitQuaternion, Vector3};
+
+        let amountcalc = AnimationAmountCalc::default();
+
+        let mut curve = FrameCurve::curve_frame_values(60);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, Vector3::new(0.0 as KeyFrameCurveValue, 1.0, 2.0));
+        FrameCurve::curve_frame_values_frame(&mut curve, 60, Vector3::new(2.0, 3.0, 4.0));
+        assert!((curve.interple(0.5, &amountcalc) - Vector3::new(1.0, 2.0, 3.0)).norm() < 0.0001);
+
+        let mut curve = FrameCurve::curve_frame_values(60);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, Point3::new(0.0 as KeyFrameCurveValue, 0.0, 0.0));
+        FrameCurve::curve_frame_values_frame(&mut curve, 60, Point3::new(4.0, 0.0, -2.0));
+        assert!((curve.interple(0.25, &amountcalc) - Point3::new(1.0, 0.0, -0.5)).norm() < 0.0001);
+        assert!((Point3::new(0.0 as KeyFrameCurveValue, 0.0, 0.0).distance(&Point3::new(3.0, 4.0, 0.0)) - 5.0).abs() < 0.0001);
+
+        // slerp 匀速旋转
+        let axis = Vector3::z_axis();
+        let mut curve = FrameCurve::curve_frame_values(60);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, UnitQuaternion::from_axis_angle(&axis, 0.0 as KeyFrameCurveValue));
+        FrameCurve::curve_frame_values_frame(&mut curve, 60, UnitQuaternion::from_axis_angle(&axis, 2.0));
+        let value = curve.interple(0.25, &amountcalc);
+        assert!((value.angle() - 0.5).abs() < 0.0001);
+        // 相同旋转的相反表示
+        let rotation = UnitQuaternion::from_axis_angle(&axis, 1.0 as KeyFrameCurveValue);
+        let negative = UnitQuaternion::new_unchecked(-rotation.into_inner());
+        assert!(rotation.interpolate(&negative, 0.5).angle_to(&rotation) < 0.001);
+    }
+
+    #[test]
+    fn test_integer_rounding() {
+        let amountcalc = AnimationAmountCalc::default();
+
+        // 0 -> 255 逐帧均匀取整
+        let mut curve = FrameCurve::curve_frame_values(255);
+        FrameCurve::curve_frame_values_frame(&mut curve, 0, 0u8);
+        FrameCurve::curve_frame_values_frame(&mut curve, 255, 255u8);
+        for frame in 0..=255u16 {
+            assert_eq!(curve.interple(frame as KeyFrameCurveValue / 255., &amountcalc) as u16, frame);
+        }
+        assert_eq!(0u8.interpolate(&255, 0.5), 128);
+        assert_eq!(10u8.interpolate(&13, 0.5), 12);
+
+        // 有符号整数
+        assert_eq!((-10i32).interpolate(&10, 0.25), -5);
+        assert_eq!((-3i8).interpolate(&4, 0.5), 1);
+        assert_eq!(i64::MIN.interpolate(&0, 1.0), 0);
+
+        // 饱和而不是回绕
+        assert_eq!(250u8.append(&10, 1.0), 255);
+        assert_eq!(5u8.append(&10, -1.0), 0);
+        assert_eq!(120i8.append(&100, 1.0), 127);
+        let value = <u8 as FrameDataValue>::hermite(&200, &255, &255, &0, 0.5, 1.0);
+        assert_eq!(value, 255);
+        assert_eq!(200u8.scale(2.0), 255);
+        assert_eq!((-100i8).scale(2.0), -128);
+
+        // 宽于 24 位的整数在 f64 中计算
+        let base = 1u64 << 40;
+        assert_eq!(base.interpolate(&(base + 2), 0.5), base + 1);
+        assert_eq!((u32::MAX - 2).interpolate(&u32::MAX, 0.5), u32::MAX - 1);
+        assert_eq!(i64::MAX.append(&1, 1.0), i64::MAX);
+
+        // 取整方式
+        let floor = Rounded::<u8, RoundFloor>::new(10).interpolate(&Rounded::new(13), 0.5);
+        let ceil = Rounded::<u8, RoundCeil>::new(10).interpolate(&Rounded::new(13), 0.5);
+        let nearest = Rounded::<u8, RoundNearest>::new(10).interpolate(&Rounded::new(13), 0.5);
+        assert_eq!((floor.value, nearest.value, ceil.value), (11, 12, 12));
+        let value = Rounded::<i16, RoundFloor>::new(0).interpolate(&Rounded::new(-3), 0.5);
+        assert_eq!(value.value, -2);
+        let value = Rounded::<u16, RoundCeil>::new(u16::MAX).append(&Rounded::new(1), 1.0);
+        assert_eq!(value.value, u16::MAX);
+    }
+
+    #[test]
+    fn test_discrete_curve() {
+        #[derive(Debug, Clone, PartialEq)]
+        enum State { Idle, Run, Jump }
+
+        // 可见性开关
+        let mut visible = DiscreteCurve::new(60);
+        visible.curve_frame(0, true);
+        visible.curve_frame(30, false);
+        visible.curve_frame(60, true);
+        assert_eq!(visible.interple(0.0), Some(&true));
+        assert_eq!(visible.interple(0.25), Some(&true));
+        assert_eq!(visible.interple(0.5), Some(&false));
+        assert_eq!(visible.interple(0.99), Some(&false));
+        assert_eq!(visible.interple(1.5), Some(&true));
+        assert_eq!(visible.interple(-1.0), Some(&true));
+        assert_eq!(DiscreteCurve::<bool>::new(60).interple(0.5), None);
+
+        // 取值方式与循环
+        let mut state = DiscreteCurve::new_with_mode(10, EFrameStepMode::Next);
+        state.curve_frame(0, State::Idle);
+        state.curve_frame(10, State::Run);
+        state.curve_frame(20, State::Jump);
+        assert_eq!(state.interple(0.0), Some(&State::Idle));
+        assert_eq!(state.interple(0.05), Some(&State::Run));
+        assert_eq!(state.interple(1.0), Some(&State::Run));
+        state.step_mode = EFrameStepMode::Nearest;
+        assert_eq!(state.interple(0.4), Some(&State::Idle));
+        assert_eq!(state.interple(0.6), Some(&State::Run));
+        state.set_wrap_mode(ECurveWrapMode::Constant, ECurveWrapMode::Loop);
+        assert_eq!(state.interple(2.1), Some(&State::Idle));
+        assert_eq!(state.key_index(34.), Some(1));
+
+        // 字符串
+        let mut texture = DiscreteCurve::new(30);
+        texture.curve_frame(0, String::from("idle.png"));
+        texture.curve_frame(15, String::from("hit.png"));
+        assert_eq!(texture.interple(0.6).map(|v| v.as_str()), Some("hit.png"));
+    }
+
+    #[test]
+    fn test_event_track() {
+        // 60 帧的循环动画, 第 10 / 40 帧脚步声, 第 60 帧结束
+        let mut track = EventTrack::new(60, ECurveWrapMode::Loop);
+        track.add_event(10, "left");
+        track.add_event(40, "right");
+        track.add_event(60, "end");
+        assert_eq!(track.crossed_events(0.0, 0.5), vec![&"left"]);
+        assert_eq!(track.crossed_events(0.5, 0.5), Vec::<&&str>::new());
+        // 区间端点: 正向 (previous, current]
+        assert_eq!(track.crossed_frames(10., 40.), vec![1]);
+        // 跨越循环
+        assert_eq!(track.crossed_frames(50., 75.), vec![2, 0]);
+        // 大跨度跳转, 首尾周期之间只保留一个完整周期
+        assert_eq!(track.crossed(0.0, 2.5), vec![0, 1, 2, 0]);
+        assert_eq!(track.crossed_frames(0., 60_000_015.), vec![0, 1, 2, 0]);
+        assert_eq!(track.crossed_frames(75., -120.), vec![0, 2, 1, 0, 2]);
+        assert_eq!(track.crossed_frames(-30., 15.), vec![1, 2, 0]);
+        // 倒放 [current, previous)
+        assert_eq!(track.crossed_frames(75., 40.), vec![0, 2, 1]);
+        assert_eq!(track.crossed_frames(130., 100.), vec![2, 1]);
+
+        // 往返循环, 折返点只触发一次
+        let mut track = EventTrack::new(60, ECurveWrapMode::PingPong);
+        track.add_event(0, "start");
+        track.add_event(15, "mid");
+        track.add_event(30, "end");
+        assert_eq!(track.crossed_frames(0., 30.), vec![1, 2]);
+        assert_eq!(track.crossed_frames(0., 90.), vec![1, 2, 1, 0, 1, 2]);
+
+        // 不循环, 区间外没有事件
+        let mut track = EventTrack::new(30, ECurveWrapMode::Constant);
+        track.add_event(15, String::from("spawn"));
+        track.set_range(0, 30);
+        assert_eq!(track.crossed_events(0.0, 10.0), vec![&String::from("spawn")]);
+        assert!(track.crossed_events(1.0, 10.0).is_empty());
+        assert_eq!(track.crossed_frames(30., 0.), vec![0]);
+    }
+
     #[bench]
     fn test_minmaxcurve_peformance(b: &mut Bencher) {
     
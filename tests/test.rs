@@ -1,4 +1,3 @@
-use anim_curve;
 
 extern crate simba;
 
@@ -15,7 +14,7 @@ fn test() {
     // let d = 101;
     // let df = (d - 1) as f32;
     // for i in 0..d {
-    //     let result = anim_curve::bezier::cubic_bezier(x1, y1, x2, y2, (d - 1 - i) as f32 / df);
+    //     let result = anim_curve::cubic_bezier(x1, y1, x2, y2, (d - 1 - i) as f32 / df);
     //     let idx = (result * df) as usize;
     //     let mut str = String::from("");
     //     for j in 0..d {
@@ -29,6 +28,6 @@ fn test() {
     // }
 
     
-    let result = anim_curve::bezier::cubic_bezier(x1, y1, x2, y2, 1.);
+    let result = anim_curve::cubic_bezier(x1, y1, x2, y2, 1.);
     println!("{}", result);
 }
\ No newline at end of file